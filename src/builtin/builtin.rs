@@ -1,18 +1,339 @@
 use crate::*;
-use {Function, Functions, Value, to_value};
+use {Function, Functions, Overflow, Value, to_value};
 use Error;
-use self::core::math::math::Math;
+use self::core::math::math::{self, Math, as_f64, as_object, check_length, equals, is_integer, is_number, stringify,
+                              truthy};
+#[cfg(feature = "bigint")]
+use self::core::math::math::{big_integer, big_value};
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::fmt;
+use tree::slice;
+#[cfg(feature = "regex")]
+use regex::Regex;
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+#[cfg(feature = "base64")]
+use base64::Engine;
+#[cfg(feature = "base64")]
+use base64::engine::general_purpose::STANDARD as BASE64;
+#[cfg(feature = "hash")]
+use sha2::Digest;
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Datelike, FixedOffset, Months, NaiveDate, NaiveDateTime, SecondsFormat, TimeDelta, TimeZone,
+             Timelike, Utc};
+#[cfg(feature = "chrono")]
+use chrono::format::{Item, StrftimeItems};
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::Tz;
+#[cfg(feature = "regex")]
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub struct BuiltIn {}
 
+/// A lambda argument like `x => x * 2`, called with its parameters in order.
+pub type Lambda<'a> = dyn Fn(&[Value]) -> Result<Value, Error> + 'a;
+
+/// A builtin whose last argument is a lambda, e.g. `peta(arr, x => x * 2)`.
+pub struct HigherOrder {
+    /// Number of arguments in front of the lambda.
+    pub args: usize,
+    pub compiled: fn(Vec<Value>, &Lambda) -> Result<Value, Error>,
+}
+
+/// Evaluates the argument at the given index, for builtins that take them lazily.
+pub type Argument<'a> = dyn Fn(usize) -> Result<Value, Error> + 'a;
+
+/// A builtin that evaluates its arguments only when it uses them, e.g. the
+/// fallback of `bawaan(x, fallback)`.
+pub struct Lazy {
+    pub args: usize,
+    pub compiled: fn(&Argument) -> Result<Value, Error>,
+}
+
+/// A builtin folding numbers and arrays of numbers, e.g. `jumlah(1, [2, 3])`,
+/// with integer overflow handled by the configured mode.
+pub struct Aggregate {
+    pub identity: i64,
+    pub fold: fn(&Value, &Value, Overflow) -> Result<Value, Error>,
+}
+
+impl Aggregate {
+    pub fn exec(&self, values: Vec<Value>, overflow: Overflow) -> Result<Value, Error> {
+        if values.is_empty() {
+            return Err(Error::ArgumentsLess(1));
+        }
+
+        let mut result = to_value(self.identity);
+        for value in values {
+            let items = match value {
+                Value::Array(array) => array,
+                value => vec![value],
+            };
+            for item in items {
+                if !is_number(&item) {
+                    return Err(Error::ExpectedNumber);
+                }
+                result = (self.fold)(&result, &item, overflow)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
 impl BuiltIn {
+    pub fn aggregate(ident: &str) -> Option<Aggregate> {
+        match ident {
+            "jumlah" => Some(Aggregate { identity: 0, fold: math::add }),
+            "kali_semua" => Some(Aggregate { identity: 1, fold: math::multiply }),
+            _ => None,
+        }
+    }
+
+    pub fn lazy(ident: &str) -> Option<Lazy> {
+        match ident {
+            "bawaan" => Some(Lazy { args: 2, compiled: default }),
+            _ => None,
+        }
+    }
+
+    pub fn higher_order(ident: &str) -> Option<HigherOrder> {
+        match ident {
+            "hitung_jika" => Some(HigherOrder { args: 1, compiled: count_if }),
+            "ada" => Some(HigherOrder { args: 1, compiled: any }),
+            "semua" => Some(HigherOrder { args: 1, compiled: all }),
+            "peta" => Some(HigherOrder { args: 1, compiled: map }),
+            "saring" => Some(HigherOrder { args: 1, compiled: filter }),
+            "lipat" => Some(HigherOrder { args: 2, compiled: fold }),
+            "kelompokkan" => Some(HigherOrder { args: 1, compiled: group_by_lambda }),
+            "cari" => Some(HigherOrder { args: 1, compiled: find }),
+            "cari_indeks" => Some(HigherOrder { args: 1, compiled: find_index }),
+            "min_oleh" => Some(HigherOrder { args: 1, compiled: |values, lambda| {
+                extreme_by_lambda(values, lambda, Ordering::Less)
+            } }),
+            "maks_oleh" => Some(HigherOrder { args: 1, compiled: |values, lambda| {
+                extreme_by_lambda(values, lambda, Ordering::Greater)
+            } }),
+            _ => None,
+        }
+    }
+
     pub fn new() -> Functions {
         let mut functions = Functions::new();
         functions.insert("min".to_owned(), create_min_fuction());
         functions.insert("max".to_owned(), create_max_fuction());
         functions.insert("panjang".to_owned(), create_len_fuction());
+        #[cfg(feature = "unicode-segmentation")]
+        functions.insert("panjang_grafem".to_owned(), create_grapheme_len_function());
         functions.insert("kosong".to_owned(), create_is_empty_fuction());
         functions.insert("untaian".to_owned(), create_array_function());
+        functions.insert("rentang".to_owned(), create_range_function());
+        functions.insert("urutkan".to_owned(), create_sort_function());
+        functions.insert("urutkan_oleh".to_owned(), create_sort_by_function());
+        functions.insert("balik".to_owned(), map_array(|array| {
+            Ok(Value::Array(array.iter().rev().cloned().collect()))
+        }));
+        functions.insert("pertama".to_owned(), map_array(|array| {
+            Ok(array.first().cloned().unwrap_or(Value::Null))
+        }));
+        functions.insert("terakhir".to_owned(), map_array(|array| {
+            Ok(array.last().cloned().unwrap_or(Value::Null))
+        }));
+        functions.insert("unik".to_owned(), map_array(|array| Ok(unique_by(array, |item| item))));
+        functions.insert("unik_oleh".to_owned(), create_unique_by_function());
+        functions.insert("irisan".to_owned(), create_set_function(|a, b| keep(a, b, true)));
+        functions.insert("gabungan_set".to_owned(), create_set_function(|a, b| {
+            unique_by(&[a, b].concat(), |item| item)
+        }));
+        functions.insert("selisih".to_owned(), create_set_function(|a, b| keep(a, b, false)));
+        functions.insert("memuat_semua".to_owned(), create_set_function(|a, b| {
+            to_value(b.iter().all(|item| contains(a, item)))
+        }));
+        functions.insert("ratakan".to_owned(), create_flatten_function());
+        functions.insert("potong_kelompok".to_owned(), create_chunk_function());
+        functions.insert("zip".to_owned(), create_zip_function());
+        functions.insert("kelompokkan".to_owned(), create_group_by_function());
+        functions.insert("min_oleh".to_owned(), create_extreme_by_function(Ordering::Less));
+        functions.insert("maks_oleh".to_owned(), create_extreme_by_function(Ordering::Greater));
+        functions.insert("sambung".to_owned(), create_concat_function());
+        functions.insert("tambah".to_owned(), create_append_function());
+        functions.insert("sisip".to_owned(), create_insert_function());
+        functions.insert("hapus_indeks".to_owned(), create_remove_index_function());
+        functions.insert("ambil".to_owned(), create_slice_function(|n| (Value::Null, n)));
+        functions.insert("lewati".to_owned(), create_slice_function(|n| (n, Value::Null)));
+        functions.insert("iris".to_owned(), create_iris_function());
+        functions.insert("enumerasi".to_owned(), map_array(|array| {
+            Ok(Value::Array(array.iter().enumerate().map(|(index, item)| {
+                Value::Array(vec![to_value(index), item.clone()])
+            }).collect()))
+        }));
+        functions.insert("kunci".to_owned(), map_object(|object| {
+            Ok(Value::Array(object.keys().map(to_value).collect()))
+        }));
+        functions.insert("nilai".to_owned(), map_object(|object| {
+            Ok(Value::Array(object.values().cloned().collect()))
+        }));
+        functions.insert("entri".to_owned(), map_object(|object| {
+            Ok(Value::Array(object.iter().map(|(key, value)| to_value((key, value))).collect()))
+        }));
+        functions.insert("dari_entri".to_owned(), map_array(|pairs| {
+            let mut object = serde_json::Map::new();
+            for pair in pairs {
+                match pair.as_array().map(Vec::as_slice) {
+                    Some([key, value]) => object.insert(stringify(key), value.clone()),
+                    _ => return Err(Error::ExpectedArray),
+                };
+            }
+            Ok(Value::Object(object))
+        }));
+        functions.insert("tipe".to_owned(), create_type_function());
+        functions.insert("adalah_angka".to_owned(), create_is_type_function("angka"));
+        functions.insert("adalah_teks".to_owned(), create_is_type_function("teks"));
+        functions.insert("adalah_larik".to_owned(), create_is_type_function("larik"));
+        functions.insert("adalah_objek".to_owned(), create_is_type_function("objek"));
+        functions.insert("adalah_bool".to_owned(), create_is_type_function("boolean"));
+        functions.insert("adalah_nihil".to_owned(), create_is_type_function("nihil"));
+        functions.insert("punya".to_owned(), create_has_function());
+        functions.insert("ambil_nilai".to_owned(), create_get_function());
+        functions.insert("jalur".to_owned(), create_path_function());
+        functions.insert("pilih".to_owned(), create_pick_function(true));
+        functions.insert("buang".to_owned(), create_pick_function(false));
+        functions.insert("gabung_dalam".to_owned(), create_deep_merge_function());
+        functions.insert("setel".to_owned(), create_set_field_function());
+        functions.insert("setel_jalur".to_owned(), create_set_path_function());
+        functions.insert("mutlak".to_owned(), create_abs_function());
+        functions.insert("tanda".to_owned(), create_sign_function());
+        functions.insert("lantai".to_owned(), map_rounding(f64::floor));
+        functions.insert("langit".to_owned(), map_rounding(f64::ceil));
+        functions.insert("bulatkan".to_owned(), create_round_function());
+        functions.insert("akar".to_owned(), map_number(|number| finite(number.sqrt())));
+        functions.insert("pangkat".to_owned(), create_pow_function());
+        functions.insert("eksp".to_owned(), map_number(|number| finite(number.exp())));
+        functions.insert("log".to_owned(), map_number(|number| finite(number.ln())));
+        functions.insert("log10".to_owned(), map_number(|number| finite(number.log10())));
+        functions.insert("log2".to_owned(), map_number(|number| finite(number.log2())));
+        functions.insert("sin".to_owned(), map_number(|number| finite(number.sin())));
+        functions.insert("cos".to_owned(), map_number(|number| finite(number.cos())));
+        functions.insert("tan".to_owned(), map_number(|number| finite(number.tan())));
+        functions.insert("asin".to_owned(), map_number(|number| finite(number.asin())));
+        functions.insert("acos".to_owned(), map_number(|number| finite(number.acos())));
+        functions.insert("atan".to_owned(), map_number(|number| finite(number.atan())));
+        functions.insert("atan2".to_owned(), create_atan2_function());
+        functions.insert("derajat".to_owned(), map_number(|number| finite(number.to_degrees())));
+        functions.insert("radian".to_owned(), map_number(|number| finite(number.to_radians())));
+        functions.insert("pi".to_owned(), create_constant_function(std::f64::consts::PI));
+        functions.insert("e".to_owned(), create_constant_function(std::f64::consts::E));
+        insert_random_functions(&mut functions, &Rng::from_time());
+        insert_clock_functions(&mut functions, None);
+        functions.insert("jepit".to_owned(), create_clamp_function());
+        functions.insert("lerp".to_owned(), create_lerp_function());
+        functions.insert("fpb".to_owned(), create_gcd_function());
+        functions.insert("kpk".to_owned(), create_lcm_function());
+        functions.insert("faktorial".to_owned(), create_factorial_function());
+        functions.insert("rata".to_owned(), map_numbers(|numbers| mean(numbers)));
+        functions.insert("median".to_owned(), map_numbers(|numbers| percentile(numbers, 50.0)));
+        functions.insert("simpangan_baku".to_owned(), map_numbers(|numbers| std_dev(numbers)));
+        functions.insert("persentil".to_owned(), create_percentile_function());
+        functions.insert("huruf_besar".to_owned(), create_uppercase_function());
+        functions.insert("upper".to_owned(), create_uppercase_function());
+        functions.insert("huruf_kecil".to_owned(), create_lowercase_function());
+        functions.insert("lower".to_owned(), create_lowercase_function());
+        functions.insert("pangkas".to_owned(), create_trim_function());
+        functions.insert("pangkas_kiri".to_owned(), create_trim_start_function());
+        functions.insert("pangkas_kanan".to_owned(), create_trim_end_function());
+        functions.insert("pisah".to_owned(), create_split_function());
+        functions.insert("gabung".to_owned(), create_join_function());
+        functions.insert("ganti".to_owned(), create_replace_function());
+        #[cfg(feature = "regex")]
+        {
+            let regexes = RegexCache::default();
+            functions.insert("ganti_regex".to_owned(), create_replace_regex_function(&regexes));
+            functions.insert("cocok".to_owned(), create_is_match_function(&regexes));
+            functions.insert("tangkap".to_owned(), create_captures_function(&regexes));
+            functions.insert("cari_semua".to_owned(), create_find_all_function(&regexes));
+        }
+        functions.insert("potong".to_owned(), create_substring_function());
+        functions.insert("karakter".to_owned(), create_char_at_function());
+        functions.insert("berisi".to_owned(), create_contains_function());
+        functions.insert("diawali".to_owned(), create_starts_with_function());
+        functions.insert("diakhiri".to_owned(), create_ends_with_function());
+        functions.insert("posisi".to_owned(), create_index_of_function(false));
+        functions.insert("posisi_akhir".to_owned(), create_index_of_function(true));
+        functions.insert("isi_kiri".to_owned(), create_pad_function(true));
+        functions.insert("isi_kanan".to_owned(), create_pad_function(false));
+        functions.insert("format".to_owned(), create_format_function());
+        functions.insert("ke_angka".to_owned(), create_parse_number_function(parse_number));
+        functions.insert("ke_bulat".to_owned(), create_parse_number_function(Value::cast_int));
+        functions.insert("ke_pecahan".to_owned(), create_parse_number_function(Value::cast_float));
+        functions.insert("angka_lokal".to_owned(), create_local_number_function());
+        functions.insert("format_angka".to_owned(), create_format_number_function());
+        functions.insert("rupiah".to_owned(), create_rupiah_function());
+        functions.insert("ke_romawi".to_owned(), create_to_roman_function());
+        functions.insert("dari_romawi".to_owned(), create_from_roman_function());
+        functions.insert("balik_teks".to_owned(), create_reverse_string_function());
+        functions.insert("ulang".to_owned(), create_repeat_function());
+        functions.insert("kapital".to_owned(), create_capitalize_function());
+        functions.insert("judul".to_owned(), create_title_case_function());
+        functions.insert("slug".to_owned(), create_slug_function());
+        functions.insert("banding".to_owned(), create_compare_function());
+        functions.insert("banding_alami".to_owned(), create_natural_compare_function());
+        functions.insert("ke_bool".to_owned(), create_to_bool_function());
+        functions.insert("ke_teks".to_owned(), create_to_string_function());
+        functions.insert("ke_json".to_owned(), create_to_json_function());
+        functions.insert("dari_json".to_owned(), create_from_json_function());
+        functions.insert("url_enkode".to_owned(), create_url_encode_function());
+        functions.insert("url_dekode".to_owned(), create_url_decode_function());
+        #[cfg(feature = "base64")]
+        functions.insert("base64_enkode".to_owned(), map_string(|string| BASE64.encode(string)));
+        #[cfg(feature = "base64")]
+        functions.insert("base64_dekode".to_owned(),
+                         decode_string(|string| BASE64.decode(string).map_err(|err| err.to_string())));
+        #[cfg(feature = "hex")]
+        functions.insert("hex_enkode".to_owned(), map_string(|string| hex::encode(string)));
+        #[cfg(feature = "hex")]
+        functions.insert("hex_dekode".to_owned(),
+                         decode_string(|string| hex::decode(string).map_err(|err| err.to_string())));
+        #[cfg(feature = "hash")]
+        {
+            functions.insert("hash_md5".to_owned(), create_hash_function::<md5::Md5>());
+            functions.insert("hash_sha1".to_owned(), create_hash_function::<sha1::Sha1>());
+            functions.insert("hash_sha256".to_owned(), create_hash_function::<sha2::Sha256>());
+            functions.insert("hash_sha512".to_owned(), create_hash_function::<sha2::Sha512>());
+            functions.insert("hash_bucket".to_owned(), create_hash_bucket_function());
+        }
+        #[cfg(feature = "uuid")]
+        {
+            functions.insert("uuid".to_owned(), create_uuid_function());
+            functions.insert("uuid_v5".to_owned(), create_uuid_v5_function());
+        }
+        #[cfg(feature = "chrono")]
+        {
+            functions.insert("tanggal".to_owned(), create_date_function());
+            functions.insert("format_tanggal".to_owned(), create_format_date_function(false));
+            functions.insert("format_tanggal_id".to_owned(), create_format_date_function(true));
+            functions.insert("tambah_hari".to_owned(), create_add_date_function(|time, days| {
+                TimeDelta::try_days(days).and_then(|delta| time.checked_add_signed(delta))
+            }));
+            // the day is clamped to the end of shorter months, `31 Januari` + 1 is `29 Februari`
+            functions.insert("tambah_bulan".to_owned(), create_add_date_function(|time, months| {
+                let delta = Months::new(months.unsigned_abs().min(u32::MAX as u64) as u32);
+                if months < 0 { time.checked_sub_months(delta) } else { time.checked_add_months(delta) }
+            }));
+            functions.insert("selisih_hari".to_owned(), create_date_diff_function(TimeDelta::num_days));
+            functions.insert("selisih_jam".to_owned(), create_date_diff_function(TimeDelta::num_hours));
+            functions.insert("tahun".to_owned(), create_date_part_function(|time| time.year() as i64));
+            functions.insert("bulan".to_owned(), create_date_part_function(|time| time.month() as i64));
+            functions.insert("hari".to_owned(), create_date_part_function(|time| time.day() as i64));
+            functions.insert("jam".to_owned(), create_date_part_function(|time| time.hour() as i64));
+            functions.insert("menit".to_owned(), create_date_part_function(|time| time.minute() as i64));
+            functions.insert("hari_minggu".to_owned(), create_weekday_function());
+        }
+        #[cfg(feature = "chrono-tz")]
+        functions.insert("ke_zona".to_owned(), create_to_zone_function());
         functions
     }
 }
@@ -76,7 +397,7 @@ fn compare(compare: Compare) -> Function {
     }
 }
 
-
+/// Folds numeric args, arrays are flattened one level like in min/max.
 fn create_is_empty_fuction() -> Function {
     Function {
         max_args: Some(1),
@@ -84,13 +405,14 @@ fn create_is_empty_fuction() -> Function {
         compiled: Box::new(|values| match *values.first().unwrap() {
             Value::String(ref string) => Ok(to_value(string.is_empty())),
             Value::Array(ref array) => Ok(to_value(array.is_empty())),
-            Value::Object(ref object) => Ok(to_value(object.is_empty())),
+            Value::Object(ref object) if !is_number(&values[0]) => Ok(to_value(object.is_empty())),
             Value::Null => Ok(to_value(true)),
             _ => Ok(to_value(false)),
         }),
     }
 }
 
+/// Length of a string in chars (not bytes), so `panjang('é')` is 1.
 fn create_len_fuction() -> Function {
     Function {
         max_args: Some(1),
@@ -98,9 +420,9 @@ fn create_len_fuction() -> Function {
         compiled: Box::new(|values| {
             let value = values.first().unwrap();
             match *value {
-                Value::String(ref string) => Ok(to_value(string.len())),
+                Value::String(ref string) => Ok(to_value(string.chars().count())),
                 Value::Array(ref array) => Ok(to_value(array.len())),
-                Value::Object(ref object) => Ok(to_value(object.len())),
+                Value::Object(ref object) if !is_number(value) => Ok(to_value(object.len())),
                 Value::Null => Ok(to_value(0)),
                 _ => {
                     Err(Error::Custom(format!("len() only accept string, array, object and \
@@ -112,6 +434,2291 @@ fn create_len_fuction() -> Function {
     }
 }
 
+/// Length of a string in user-perceived characters, so a flag emoji or a
+/// letter with a combining accent counts as 1.
+#[cfg(feature = "unicode-segmentation")]
+fn create_grapheme_len_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| match values[0] {
+            Value::String(ref string) => Ok(to_value(string.graphemes(true).count())),
+            Value::Null => Ok(to_value(0)),
+            _ => Err(Error::ExpectedString),
+        }),
+    }
+}
+
+fn create_abs_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            #[cfg(feature = "bigint")]
+            {
+                if let Some(number) = big_integer(&values[0]) {
+                    return Ok(big_value(number.magnitude().clone().into()));
+                }
+            }
+
+            match values[0].as_i64() {
+                Some(number) => Ok(to_value(number.unsigned_abs())),
+                None if values[0].is_u64() => Ok(values[0].clone()),
+                None => Ok(to_value(as_f64(&values[0]).ok_or(Error::ExpectedNumber)?.abs())),
+            }
+        }),
+    }
+}
+
+fn create_sign_function() -> Function {
+    map_number(|number| {
+        Ok(to_value(if number > 0.0 {
+            1
+        } else if number < 0.0 {
+            -1
+        } else {
+            0
+        }))
+    })
+}
+
+/// `bulatkan(x)` rounds half away from zero to an integer, `bulatkan(x, digits)`
+/// keeps that many decimals (negative digits round to tens, hundreds, ...).
+/// Integers, also big ones, are returned as is by `bulatkan(x)`.
+fn create_round_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let number = as_f64(&values[0]).ok_or(Error::ExpectedNumber)?;
+            let digits = match values.get(1) {
+                Some(digits) => digits.as_i64().ok_or(Error::ExpectedNumber)?,
+                None => 0,
+            };
+            if digits == 0 && is_integer(&values[0]) {
+                return Ok(values[0].clone());
+            }
+            let rounded = round_half_away(number, digits);
+            Ok(if digits <= 0 { integral(rounded) } else { to_value(rounded) })
+        }),
+    }
+}
+
+/// Rounds half away from zero to `digits` decimals, the rule of both `bulatkan`
+/// and `format_angka`. Digits finer than f64 holds, like `bulatkan(1e300, 300)`,
+/// keep the number as is.
+fn round_half_away(number: f64, digits: i64) -> f64 {
+    if digits == 0 {
+        return number.round();
+    }
+    let scale = 10f64.powi(digits.clamp(-308, 308) as i32);
+    let rounded = (number * scale).round() / scale;
+    if rounded.is_finite() {
+        rounded
+    } else {
+        number
+    }
+}
+
+fn create_pow_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| values[0].pow(&values[1])),
+    }
+}
+
+fn create_constant_function(constant: f64) -> Function {
+    Function {
+        max_args: Some(0),
+        min_args: Some(0),
+        compiled: Box::new(move |_| Ok(to_value(constant))),
+    }
+}
+
+/// Splitmix64 state shared by the `acak` builtins of one function table.
+#[derive(Clone)]
+struct Rng(Arc<Mutex<u64>>);
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        Rng(Arc::new(Mutex::new(seed)))
+    }
+
+    fn from_time() -> Rng {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos()).unwrap_or(0);
+        Rng::new(nanos as u64)
+    }
+
+    /// Uniform float in `0..1`.
+    fn next(&self) -> f64 {
+        let mut state = self.0.lock().unwrap();
+        *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Random builtins driven by a fixed seed, set through `ExecOptions::seed`.
+pub struct Random(Arc<Functions>);
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        let mut functions = Functions::new();
+        insert_random_functions(&mut functions, &Rng::new(seed));
+        Random(Arc::new(functions))
+    }
+
+    pub fn get(&self, ident: &str) -> Option<&Function> {
+        self.0.get(ident)
+    }
+}
+
+impl Clone for Random {
+    fn clone(&self) -> Random {
+        Random(self.0.clone())
+    }
+}
+
+impl fmt::Debug for Random {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Random")
+    }
+}
+
+fn insert_random_functions(functions: &mut Functions, rng: &Rng) {
+    functions.insert("acak".to_owned(), create_random_function(rng.clone()));
+    functions.insert("acak_antara".to_owned(), create_random_between_function(rng.clone()));
+    functions.insert("acak_pilih".to_owned(), create_random_choice_function(rng.clone()));
+}
+
+fn create_random_function(rng: Rng) -> Function {
+    Function {
+        max_args: Some(0),
+        min_args: Some(0),
+        compiled: Box::new(move |_| Ok(to_value(rng.next()))),
+    }
+}
+
+/// Integer bounds give an integer in `a..=b`, otherwise a float in `a..b`.
+fn create_random_between_function(rng: Rng) -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            if let (Some(low), Some(high)) = (values[0].as_i64(), values[1].as_i64()) {
+                if low > high {
+                    return Err(Error::InvalidRange(format!("{}..{}", low, high)));
+                }
+                let span = (high as i128 - low as i128 + 1) as f64;
+                let offset = ((rng.next() * span) as i128).min(high as i128 - low as i128);
+                return Ok(to_value((low as i128 + offset) as i64));
+            }
+
+            let low = as_f64(&values[0]).ok_or(Error::ExpectedNumber)?;
+            let high = as_f64(&values[1]).ok_or(Error::ExpectedNumber)?;
+            if low > high {
+                return Err(Error::InvalidRange(format!("{}..{}", low, high)));
+            }
+            finite(low + rng.next() * (high - low))
+        }),
+    }
+}
+
+fn create_random_choice_function(rng: Rng) -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| {
+            let array = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            if array.is_empty() {
+                return Ok(Value::Null);
+            }
+            let index = ((rng.next() * array.len() as f64) as usize).min(array.len() - 1);
+            Ok(array[index].clone())
+        }),
+    }
+}
+
+/// Clock builtins frozen at an epoch millisecond, set through `ExecOptions::clock`.
+pub struct Clock(Arc<Functions>);
+
+impl Clock {
+    pub fn fixed(millis: i64) -> Clock {
+        let mut functions = Functions::new();
+        insert_clock_functions(&mut functions, Some(millis));
+        Clock(Arc::new(functions))
+    }
+
+    pub fn get(&self, ident: &str) -> Option<&Function> {
+        self.0.get(ident)
+    }
+}
+
+impl Clone for Clock {
+    fn clone(&self) -> Clock {
+        Clock(self.0.clone())
+    }
+}
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Clock")
+    }
+}
+
+/// `None` reads the system clock on every call.
+fn insert_clock_functions(functions: &mut Functions, fixed: Option<i64>) {
+    functions.insert("sekarang".to_owned(), create_now_function(fixed));
+    functions.insert("hari_ini".to_owned(), create_today_function(fixed));
+}
+
+fn now_millis(fixed: Option<i64>) -> i64 {
+    fixed.unwrap_or_else(|| {
+        SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_millis() as i64).unwrap_or(0)
+    })
+}
+
+/// Year, month and day of a day count since 1970-01-01, after Howard Hinnant's `civil_from_days`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn format_date(millis: i64) -> String {
+    let (year, month, day) = civil_from_days(millis.div_euclid(86_400_000));
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// UTC like `2024-05-17T08:30:00.000Z`.
+fn format_timestamp(millis: i64) -> String {
+    let time = millis.rem_euclid(86_400_000);
+    format!("{}T{:02}:{:02}:{:02}.{:03}Z",
+            format_date(millis),
+            time / 3_600_000,
+            time / 60_000 % 60,
+            time / 1000 % 60,
+            time % 1000)
+}
+
+/// `sekarang()` is an ISO-8601 UTC timestamp, `sekarang('milidetik')` the epoch milliseconds.
+fn create_now_function(fixed: Option<i64>) -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(0),
+        compiled: Box::new(move |values| {
+            let millis = now_millis(fixed);
+            match values.first().map(|format| format.as_str().ok_or(Error::ExpectedString)) {
+                None | Some(Ok("iso")) => Ok(to_value(format_timestamp(millis))),
+                Some(Ok("milidetik")) => Ok(to_value(millis)),
+                Some(Ok(format)) => Err(Error::Custom(format!("Unknown time format: {}", format))),
+                Some(Err(err)) => Err(err),
+            }
+        }),
+    }
+}
+
+/// `hari_ini()` is the UTC date like `2024-05-17`.
+fn create_today_function(fixed: Option<i64>) -> Function {
+    Function {
+        max_args: Some(0),
+        min_args: Some(0),
+        compiled: Box::new(move |_| Ok(to_value(format_date(now_millis(fixed))))),
+    }
+}
+
+fn create_clamp_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(3),
+        compiled: Box::new(|values| {
+            if !values.iter().all(Value::is_number) {
+                return Err(Error::ExpectedNumber);
+            }
+            if math::compare(&values[1], &values[2])? == Ordering::Greater {
+                return Err(Error::InvalidRange(format!("{}..{}", values[1], values[2])));
+            }
+            if math::compare(&values[0], &values[1])? == Ordering::Less {
+                Ok(values[1].clone())
+            } else if math::compare(&values[0], &values[2])? == Ordering::Greater {
+                Ok(values[2].clone())
+            } else {
+                Ok(values[0].clone())
+            }
+        }),
+    }
+}
+
+fn create_lerp_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(3),
+        compiled: Box::new(|values| {
+            let a = as_f64(&values[0]).ok_or(Error::ExpectedNumber)?;
+            let b = as_f64(&values[1]).ok_or(Error::ExpectedNumber)?;
+            let t = as_f64(&values[2]).ok_or(Error::ExpectedNumber)?;
+            finite(a + (b - a) * t)
+        }),
+    }
+}
+
+fn integer_args(values: &[Value]) -> Result<Vec<i64>, Error> {
+    values.iter().map(|value| value.as_i64().ok_or(Error::ExpectedNumber)).collect()
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+fn create_gcd_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let args = integer_args(&values)?;
+            Ok(to_value(gcd(args[0].unsigned_abs(), args[1].unsigned_abs())))
+        }),
+    }
+}
+
+fn create_lcm_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let args = integer_args(&values)?;
+            let (a, b) = (args[0].unsigned_abs(), args[1].unsigned_abs());
+            if a == 0 || b == 0 {
+                return Ok(to_value(0));
+            }
+            (a / gcd(a, b))
+                .checked_mul(b)
+                .filter(|lcm| *lcm <= i64::MAX as u64)
+                .map(to_value)
+                .ok_or(Error::Overflow)
+        }),
+    }
+}
+
+/// Exact up to `20!`, larger inputs overflow i64.
+fn create_factorial_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let n = integer_args(&values)?[0];
+            if n < 0 {
+                return Err(Error::Custom(format!("Factorial of a negative number: {}", n)));
+            }
+            (1..=n)
+                .try_fold(1i64, |product, factor| product.checked_mul(factor))
+                .map(to_value)
+                .ok_or(Error::Overflow)
+        }),
+    }
+}
+
+fn numbers(value: &Value) -> Result<Vec<f64>, Error> {
+    let array = value.as_array().ok_or(Error::ExpectedArray)?;
+    array.iter().map(|value| as_f64(value).ok_or(Error::ExpectedNumber)).collect()
+}
+
+/// Builds a statistic over a numeric array, an empty array gives null.
+fn map_numbers<F>(map: F) -> Function
+    where F: 'static + Fn(&mut [f64]) -> f64 + Sync + Send
+{
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| {
+            let mut numbers = numbers(&values[0])?;
+            if numbers.is_empty() {
+                return Ok(Value::Null);
+            }
+            finite(map(&mut numbers))
+        }),
+    }
+}
+
+fn mean(numbers: &[f64]) -> f64 {
+    numbers.iter().sum::<f64>() / numbers.len() as f64
+}
+
+/// Population standard deviation.
+fn std_dev(numbers: &[f64]) -> f64 {
+    let mean = mean(numbers);
+    let variance = numbers.iter().map(|number| (number - mean).powi(2)).sum::<f64>() / numbers.len() as f64;
+    variance.sqrt()
+}
+
+/// Linear interpolation between the closest ranks, `p` is in `0..=100`.
+fn percentile(numbers: &mut [f64], p: f64) -> f64 {
+    numbers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let rank = p / 100.0 * (numbers.len() - 1) as f64;
+    let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+    numbers[low] + (numbers[high] - numbers[low]) * (rank - low as f64)
+}
+
+fn create_percentile_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let mut numbers = numbers(&values[0])?;
+            let p = as_f64(&values[1]).ok_or(Error::ExpectedNumber)?;
+            if !(0.0..=100.0).contains(&p) {
+                return Err(Error::InvalidRange(format!("persentil {}", p)));
+            }
+            if numbers.is_empty() {
+                return Ok(Value::Null);
+            }
+            finite(percentile(&mut numbers, p))
+        }),
+    }
+}
+
+fn create_atan2_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let y = as_f64(&values[0]).ok_or(Error::ExpectedNumber)?;
+            let x = as_f64(&values[1]).ok_or(Error::ExpectedNumber)?;
+            finite(y.atan2(x))
+        }),
+    }
+}
+
+/// Rejects NaN and infinite results like `akar(-1)` or `log(0)`.
+fn finite(number: f64) -> Result<Value, Error> {
+    if number.is_finite() {
+        Ok(to_value(number))
+    } else {
+        Err(Error::NotFinite)
+    }
+}
+
+/// Builds `lantai` and `langit`, integers (also big ones) are returned as is.
+fn map_rounding(round: fn(f64) -> f64) -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| {
+            if is_integer(&values[0]) {
+                return Ok(values[0].clone());
+            }
+            Ok(integral(round(as_f64(&values[0]).ok_or(Error::ExpectedNumber)?)))
+        }),
+    }
+}
+
+/// Builds a one argument numeric builtin working on the value as a float.
+fn map_number<F>(map: F) -> Function
+    where F: 'static + Fn(f64) -> Result<Value, Error> + Sync + Send
+{
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| map(as_f64(&values[0]).ok_or(Error::ExpectedNumber)?)),
+    }
+}
+
+/// A whole float as an integer value when it fits in i64, otherwise kept as float.
+fn integral(number: f64) -> Value {
+    if number.is_finite() && number >= i64::MIN as f64 && number < i64::MAX as f64 {
+        to_value(number as i64)
+    } else {
+        to_value(number)
+    }
+}
+
 fn create_array_function() -> Function {
     Function::new(|values| Ok(to_value(values)))
 }
+
+fn test(lambda: &Lambda, item: &Value) -> Result<bool, Error> {
+    match lambda(std::slice::from_ref(item))? {
+        Value::Bool(boolean) => Ok(boolean),
+        value => Err(Error::ExpectedBoolean(value)),
+    }
+}
+
+/// `bawaan(x, fallback)` gives the fallback when `x` is blank.
+fn default(arg: &Argument) -> Result<Value, Error> {
+    let value = arg(0)?;
+    if is_blank(&value) {
+        arg(1)
+    } else {
+        Ok(value)
+    }
+}
+
+/// Null, `''` and `[]` are replaced by the `bawaan` fallback.
+fn is_blank(value: &Value) -> bool {
+    match *value {
+        Value::Null => true,
+        Value::String(ref string) => string.is_empty(),
+        Value::Array(ref array) => array.is_empty(),
+        _ => false,
+    }
+}
+
+fn count_if(values: Vec<Value>, lambda: &Lambda) -> Result<Value, Error> {
+    let mut count = 0;
+    for item in values[0].as_array().ok_or(Error::ExpectedArray)? {
+        if test(lambda, item)? {
+            count += 1;
+        }
+    }
+    Ok(to_value(count))
+}
+
+fn any(values: Vec<Value>, lambda: &Lambda) -> Result<Value, Error> {
+    for item in values[0].as_array().ok_or(Error::ExpectedArray)? {
+        if test(lambda, item)? {
+            return Ok(to_value(true));
+        }
+    }
+    Ok(to_value(false))
+}
+
+fn all(values: Vec<Value>, lambda: &Lambda) -> Result<Value, Error> {
+    for item in values[0].as_array().ok_or(Error::ExpectedArray)? {
+        if !test(lambda, item)? {
+            return Ok(to_value(false));
+        }
+    }
+    Ok(to_value(true))
+}
+
+fn map(values: Vec<Value>, lambda: &Lambda) -> Result<Value, Error> {
+    let array = values[0].as_array().ok_or(Error::ExpectedArray)?;
+    let mut mapped = Vec::with_capacity(array.len());
+    for item in array {
+        mapped.push(lambda(std::slice::from_ref(item))?);
+    }
+    Ok(Value::Array(mapped))
+}
+
+fn filter(values: Vec<Value>, lambda: &Lambda) -> Result<Value, Error> {
+    let mut matching = Vec::new();
+    for item in values[0].as_array().ok_or(Error::ExpectedArray)? {
+        if test(lambda, item)? {
+            matching.push(item.clone());
+        }
+    }
+    Ok(Value::Array(matching))
+}
+
+/// Stops at the first match, later items are never passed to the lambda.
+fn position(values: &[Value], lambda: &Lambda) -> Result<Option<usize>, Error> {
+    for (index, item) in values[0].as_array().ok_or(Error::ExpectedArray)?.iter().enumerate() {
+        if test(lambda, item)? {
+            return Ok(Some(index));
+        }
+    }
+    Ok(None)
+}
+
+fn find(values: Vec<Value>, lambda: &Lambda) -> Result<Value, Error> {
+    Ok(match position(&values, lambda)? {
+        Some(index) => values[0][index].clone(),
+        None => Value::Null,
+    })
+}
+
+fn find_index(values: Vec<Value>, lambda: &Lambda) -> Result<Value, Error> {
+    Ok(match position(&values, lambda)? {
+        Some(index) => to_value(index),
+        None => to_value(-1),
+    })
+}
+
+/// `lipat(arr, initial, (acc, x) => ...)`.
+fn fold(values: Vec<Value>, lambda: &Lambda) -> Result<Value, Error> {
+    let mut acc = values[1].clone();
+    for item in values[0].as_array().ok_or(Error::ExpectedArray)? {
+        acc = lambda(&[acc, item.clone()])?;
+    }
+    Ok(acc)
+}
+
+/// The first item whose key is the smallest (`Less`) or largest (`Greater`),
+/// null for an empty array.
+fn extreme_by<K>(array: &[Value], key: K, wanted: Ordering) -> Result<Value, Error>
+    where K: Fn(&Value) -> Result<Value, Error>
+{
+    let mut extreme: Option<(&Value, Value)> = None;
+    for item in array {
+        let item_key = key(item)?;
+        let replace = match extreme {
+            Some((_, ref best)) => sort_order(&item_key, best)? == wanted,
+            None => true,
+        };
+        if replace {
+            extreme = Some((item, item_key));
+        }
+    }
+    Ok(extreme.map_or(Value::Null, |(item, _)| item.clone()))
+}
+
+fn extreme_by_lambda(values: Vec<Value>, lambda: &Lambda, wanted: Ordering) -> Result<Value, Error> {
+    extreme_by(values[0].as_array().ok_or(Error::ExpectedArray)?,
+               |item| lambda(std::slice::from_ref(item)),
+               wanted)
+}
+
+/// `min_oleh(items, 'field')`, the lambda form is `min_oleh(items, x => ...)`.
+fn create_extreme_by_function(wanted: Ordering) -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let field = values[1].as_str().ok_or(Error::ExpectedString)?;
+            let array = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            extreme_by(array, |item| match *item {
+                Value::Object(ref object) => Ok(object.get(field).cloned().unwrap_or(Value::Null)),
+                _ => Err(Error::ExpectedObject),
+            }, wanted)
+        }),
+    }
+}
+
+/// Groups items under the string form of their key, e.g. `1` and `'1'` share a group.
+fn group_by<K>(array: &[Value], key: K) -> Result<Value, Error>
+    where K: Fn(&Value) -> Result<Value, Error>
+{
+    let mut groups = serde_json::Map::new();
+    for item in array {
+        let key = stringify(&key(item)?);
+        match groups.entry(key).or_insert_with(|| Value::Array(Vec::new())) {
+            Value::Array(group) => group.push(item.clone()),
+            _ => unreachable!(),
+        }
+    }
+    Ok(Value::Object(groups))
+}
+
+fn group_by_lambda(values: Vec<Value>, lambda: &Lambda) -> Result<Value, Error> {
+    group_by(values[0].as_array().ok_or(Error::ExpectedArray)?,
+             |item| lambda(std::slice::from_ref(item)))
+}
+
+/// `kelompokkan(items, 'field')`, or with a lambda key `kelompokkan(items, x => ...)`.
+fn create_group_by_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let field = values[1].as_str().ok_or(Error::ExpectedString)?;
+            let array = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            group_by(array, |item| match *item {
+                Value::Object(ref object) => Ok(object.get(field).cloned().unwrap_or(Value::Null)),
+                _ => Err(Error::ExpectedObject),
+            })
+        }),
+    }
+}
+
+/// `rentang(end)`, `rentang(start, end)` or `rentang(start, end, step)`, the end
+/// is exclusive like `start..end` and a negative step counts down.
+fn create_range_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let args = integer_args(&values)?;
+            let (start, end, step) = match args[..] {
+                [end] => (0, end, 1),
+                [start, end] => (start, end, 1),
+                [start, end, step] => (start, end, step),
+                _ => unreachable!(),
+            };
+            if step == 0 {
+                return Err(Error::InvalidRange(format!("rentang({}, {}, {})", start, end, step)));
+            }
+
+            let span = (end as i128 - start as i128) / step as i128;
+            let remainder = (end as i128 - start as i128) % step as i128;
+            let count = if span < 0 { 0 } else { span + if remainder != 0 { 1 } else { 0 } };
+            let mut range = Vec::with_capacity(check_length(1, count as u64)?);
+            let mut current = start;
+            while (step > 0 && current < end) || (step < 0 && current > end) {
+                range.push(current);
+                current = match current.checked_add(step) {
+                    Some(next) => next,
+                    None => break,
+                };
+            }
+            Ok(to_value(range))
+        }),
+    }
+}
+
+/// Builds a one argument builtin over an array.
+fn map_array<F>(map: F) -> Function
+    where F: 'static + Fn(&[Value]) -> Result<Value, Error> + Sync + Send
+{
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| map(values[0].as_array().ok_or(Error::ExpectedArray)?)),
+    }
+}
+
+/// Builds a one argument builtin over an object, keys are visited in sorted order.
+fn map_object<F>(map: F) -> Function
+    where F: 'static + Fn(&serde_json::Map<String, Value>) -> Result<Value, Error> + Sync + Send
+{
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| map(as_object(&values[0]).ok_or(Error::ExpectedObject)?)),
+    }
+}
+
+/// `punya(obj, 'field')`, true even when the field holds null.
+fn create_has_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let object = as_object(&values[0]).ok_or(Error::ExpectedObject)?;
+            let field = values[1].as_str().ok_or(Error::ExpectedString)?;
+            Ok(to_value(object.contains_key(field)))
+        }),
+    }
+}
+
+/// `ambil_nilai(obj, 'field', default)`, the default is only used when the field is missing.
+fn create_get_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(3),
+        compiled: Box::new(|values| {
+            let object = as_object(&values[0]).ok_or(Error::ExpectedObject)?;
+            let field = values[1].as_str().ok_or(Error::ExpectedString)?;
+            Ok(object.get(field).cloned().unwrap_or_else(|| values[2].clone()))
+        }),
+    }
+}
+
+/// `pilih(obj, 'a', 'b')` keeps and `buang(obj, 'a')` drops the named fields,
+/// names may also be given as an array.
+fn create_pick_function(keep: bool) -> Function {
+    Function {
+        max_args: None,
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let object = as_object(&values[0]).ok_or(Error::ExpectedObject)?;
+            let mut names = Vec::new();
+            for value in &values[1..] {
+                match *value {
+                    Value::String(ref name) => names.push(name.as_str()),
+                    Value::Array(ref array) => for item in array {
+                        names.push(item.as_str().ok_or(Error::ExpectedString)?);
+                    },
+                    _ => return Err(Error::ExpectedString),
+                }
+            }
+            Ok(Value::Object(object.iter()
+                .filter(|&(key, _)| names.contains(&key.as_str()) == keep)
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()))
+        }),
+    }
+}
+
+/// Objects are merged key by key, any other value from `b` wins unless both
+/// sides are arrays and `concat` is set.
+fn deep_merge(a: &mut Value, b: &Value, concat: bool) {
+    if is_number(a) || is_number(b) {
+        *a = b.clone();
+        return;
+    }
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => for (key, value) in b {
+            match a.get_mut(key) {
+                Some(existing) => deep_merge(existing, value, concat),
+                None => {
+                    a.insert(key.clone(), value.clone());
+                }
+            }
+        },
+        (Value::Array(a), Value::Array(b)) if concat => a.extend(b.iter().cloned()),
+        (a, b) => *a = b.clone(),
+    }
+}
+
+/// `gabung_dalam(a, b)` or `gabung_dalam(a, b, 'sambung')` to concatenate arrays.
+fn create_deep_merge_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let concat = match values.get(2).map(|mode| mode.as_str().ok_or(Error::ExpectedString)) {
+                None | Some(Ok("ganti")) => false,
+                Some(Ok("sambung")) => true,
+                Some(Ok(mode)) => return Err(Error::Custom(format!("Unknown merge mode: {}", mode))),
+                Some(Err(err)) => return Err(err),
+            };
+            if !values[0].is_object() || !values[1].is_object() {
+                return Err(Error::ExpectedObject);
+            }
+
+            let mut merged = values[0].clone();
+            deep_merge(&mut merged, &values[1], concat);
+            Ok(merged)
+        }),
+    }
+}
+
+/// Splits `a.b[2].c` into `a`, `b`, `2`, `c`.
+fn path_segments(path: &str) -> Result<Vec<String>, Error> {
+    let invalid = || Error::InvalidPath(path.to_owned());
+    let mut segments = Vec::new();
+    if path.is_empty() {
+        return Ok(segments);
+    }
+
+    let mut rest = path;
+    loop {
+        let end = rest.find(['.', '[']).unwrap_or(rest.len());
+        if end > 0 {
+            segments.push(rest[..end].to_owned());
+        } else if !rest.starts_with('[') {
+            return Err(invalid());
+        }
+        rest = &rest[end..];
+
+        while rest.starts_with('[') {
+            let close = rest.find(']').ok_or_else(invalid)?;
+            let index = &rest[1..close];
+            if index.is_empty() || index.parse::<usize>().is_err() {
+                return Err(invalid());
+            }
+            segments.push(index.to_owned());
+            rest = &rest[close + 1..];
+        }
+
+        if rest.is_empty() {
+            return Ok(segments);
+        }
+        if !rest.starts_with('.') {
+            return Err(invalid());
+        }
+        rest = &rest[1..];
+    }
+}
+
+fn create_path_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let path = values[1].as_str().ok_or(Error::ExpectedString)?;
+            let mut current = &values[0];
+            for segment in path_segments(path)? {
+                let next = match *current {
+                    Value::Object(ref object) => object.get(&segment),
+                    Value::Array(ref array) => segment.parse::<usize>().ok().and_then(|index| array.get(index)),
+                    _ => None,
+                };
+                current = match next {
+                    Some(next) => next,
+                    None => return Ok(Value::Null),
+                };
+            }
+            Ok(current.clone())
+        }),
+    }
+}
+
+/// `setel(obj, 'field', nilai)` returns a copy with the field replaced or added.
+fn create_set_field_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(3),
+        compiled: Box::new(|mut values| {
+            let value = values.pop().unwrap();
+            let field = values[1].as_str().ok_or(Error::ExpectedString)?.to_owned();
+            let mut object = values.swap_remove(0);
+            if is_number(&object) {
+                return Err(Error::ExpectedObject);
+            }
+            object.as_object_mut().ok_or(Error::ExpectedObject)?.insert(field, value);
+            Ok(object)
+        }),
+    }
+}
+
+/// `setel_jalur(obj, 'a.b[0].c', nilai)`, missing objects along the path are
+/// created but array items must already exist.
+fn create_set_path_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(3),
+        compiled: Box::new(|mut values| {
+            let value = values.pop().unwrap();
+            let path = values[1].as_str().ok_or(Error::ExpectedString)?.to_owned();
+            let mut root = values.swap_remove(0);
+            let mut current = &mut root;
+            for segment in path_segments(&path)? {
+                current = match *current {
+                    ref number if is_number(number) => return Err(Error::ExpectedObject),
+                    Value::Object(ref mut object) => {
+                        object.entry(segment).or_insert_with(|| Value::Object(serde_json::Map::new()))
+                    }
+                    Value::Array(ref mut array) => segment.parse::<usize>().ok()
+                        .and_then(move |index| array.get_mut(index))
+                        .ok_or_else(|| Error::InvalidPath(path.clone()))?,
+                    _ => return Err(Error::ExpectedObject),
+                };
+            }
+            *current = value;
+            Ok(root)
+        }),
+    }
+}
+
+/// Keeps the first item of every distinct key, keys are compared with `==`.
+fn unique_by<K>(array: &[Value], key: K) -> Value
+    where K: Fn(&Value) -> &Value
+{
+    let mut unique: Vec<Value> = Vec::new();
+    for item in array {
+        if !unique.iter().any(|seen| equals(key(seen), key(item))) {
+            unique.push(item.clone());
+        }
+    }
+    Value::Array(unique)
+}
+
+/// `unik_oleh(items, 'field')`, items missing the field share the null key.
+fn create_unique_by_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let field = values[1].as_str().ok_or(Error::ExpectedString)?;
+            let array = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            if !array.iter().all(Value::is_object) {
+                return Err(Error::ExpectedObject);
+            }
+            Ok(unique_by(array, |item| item.get(field).unwrap_or(&Value::Null)))
+        }),
+    }
+}
+
+fn contains(array: &[Value], item: &Value) -> bool {
+    array.iter().any(|other| equals(other, item))
+}
+
+/// Distinct items of `a` that are (or are not) in `b`.
+fn keep(a: &[Value], b: &[Value], in_b: bool) -> Value {
+    let kept = a.iter().filter(|item| contains(b, item) == in_b).cloned().collect::<Vec<_>>();
+    unique_by(&kept, |item| item)
+}
+
+/// Builds a builtin over two arrays treated as sets, items are compared with `==`.
+fn create_set_function<F>(set: F) -> Function
+    where F: 'static + Fn(&[Value], &[Value]) -> Value + Sync + Send
+{
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let a = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            let b = values[1].as_array().ok_or(Error::ExpectedArray)?;
+            Ok(set(a, b))
+        }),
+    }
+}
+
+fn flatten(array: &[Value], depth: u64, flat: &mut Vec<Value>) {
+    for item in array {
+        match *item {
+            Value::Array(ref nested) if depth > 0 => flatten(nested, depth - 1, flat),
+            _ => flat.push(item.clone()),
+        }
+    }
+}
+
+/// `ratakan(arr)` flattens one level, `ratakan(arr, depth)` that many levels.
+fn create_flatten_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let array = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            let depth = match values.get(1) {
+                Some(depth) => depth.as_u64().ok_or(Error::ExpectedNumber)?,
+                None => 1,
+            };
+            let mut flat = Vec::new();
+            flatten(array, depth, &mut flat);
+            Ok(Value::Array(flat))
+        }),
+    }
+}
+
+fn create_chunk_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let array = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            match values[1].as_u64() {
+                Some(size) if size > 0 => {
+                    Ok(to_value(array.chunks(size as usize).collect::<Vec<_>>()))
+                }
+                _ => Err(Error::Custom(format!("Invalid chunk size: {}", values[1]))),
+            }
+        }),
+    }
+}
+
+/// Joins any number of arrays into a new one.
+fn create_concat_function() -> Function {
+    Function {
+        max_args: None,
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let mut joined = Vec::new();
+            for value in values {
+                match value {
+                    Value::Array(array) => joined.extend(array),
+                    _ => return Err(Error::ExpectedArray),
+                }
+            }
+            Ok(Value::Array(joined))
+        }),
+    }
+}
+
+fn create_append_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let mut array = values[0].as_array().ok_or(Error::ExpectedArray)?.clone();
+            array.push(values[1].clone());
+            Ok(Value::Array(array))
+        }),
+    }
+}
+
+/// `sisip(arr, i, x)`, a negative `i` counts from the end and an index past
+/// either end inserts there.
+fn create_insert_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(3),
+        compiled: Box::new(|values| {
+            let mut array = values[0].as_array().ok_or(Error::ExpectedArray)?.clone();
+            let index = char_index(&values[1], array.len())?.min(array.len());
+            array.insert(index, values[2].clone());
+            Ok(Value::Array(array))
+        }),
+    }
+}
+
+/// A negative index counts from the end, an index out of range leaves the
+/// array unchanged.
+fn create_remove_index_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let mut array = values[0].as_array().ok_or(Error::ExpectedArray)?.clone();
+            let index = values[1].as_i64().ok_or(Error::ExpectedNumber)?;
+            let index = if index < 0 { index + array.len() as i64 } else { index };
+            if index >= 0 && (index as usize) < array.len() {
+                array.remove(index as usize);
+            }
+            Ok(Value::Array(array))
+        }),
+    }
+}
+
+/// `ambil` and `lewati`, the count maps to slice bounds like `arr[:n]`.
+fn create_slice_function<F>(bounds: F) -> Function
+    where F: 'static + Fn(Value) -> (Value, Value) + Sync + Send
+{
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let (start, end) = bounds(values[1].clone());
+            slice(&values[0], &start, &end)
+        }),
+    }
+}
+
+/// `iris(arr, start, end)` is `arr[start:end]`, the end is optional.
+fn create_iris_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            slice(&values[0], &values[1], values.get(2).unwrap_or(&Value::Null))
+        }),
+    }
+}
+
+/// Pairs items by position, stopping at the shorter array.
+fn create_zip_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let a = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            let b = values[1].as_array().ok_or(Error::ExpectedArray)?;
+            Ok(Value::Array(a.iter().zip(b).map(|(a, b)| Value::Array(vec![a.clone(), b.clone()])).collect()))
+        }),
+    }
+}
+
+/// Null sorts first, other values follow `banding`.
+fn sort_order(a: &Value, b: &Value) -> Result<Ordering, Error> {
+    match (a, b) {
+        (Value::Null, Value::Null) => Ok(Ordering::Equal),
+        (Value::Null, _) => Ok(Ordering::Less),
+        (_, Value::Null) => Ok(Ordering::Greater),
+        _ => math::compare(a, b),
+    }
+}
+
+fn descending(direction: Option<&Value>) -> Result<bool, Error> {
+    match direction.map(|direction| direction.as_str().ok_or(Error::ExpectedString)) {
+        None => Ok(false),
+        Some(Ok("asc")) => Ok(false),
+        Some(Ok("desc")) => Ok(true),
+        Some(Ok(direction)) => Err(Error::Custom(format!("Unknown sort direction: {}", direction))),
+        Some(Err(err)) => Err(err),
+    }
+}
+
+/// Stable sort by a key, the first comparison error is returned.
+fn sort_values<K>(mut array: Vec<Value>, key: K, descending: bool) -> Result<Value, Error>
+    where K: Fn(&Value) -> &Value
+{
+    let mut error = None;
+    array.sort_by(|a, b| {
+        let order = sort_order(key(a), key(b)).unwrap_or_else(|err| {
+            error.get_or_insert(err);
+            Ordering::Equal
+        });
+        if descending { order.reverse() } else { order }
+    });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(Value::Array(array)),
+    }
+}
+
+/// `urutkan(arr)` or `urutkan(arr, 'desc')`.
+fn create_sort_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let descending = descending(values.get(1))?;
+            let array = values[0].as_array().ok_or(Error::ExpectedArray)?.clone();
+            sort_values(array, |value| value, descending)
+        }),
+    }
+}
+
+/// `urutkan_oleh(items, 'field')`, a missing field sorts like null.
+fn create_sort_by_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let field = values[1].as_str().ok_or(Error::ExpectedString)?;
+            let descending = descending(values.get(2))?;
+            let array = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            if !array.iter().all(Value::is_object) {
+                return Err(Error::ExpectedObject);
+            }
+            sort_values(array.clone(), |item| item.get(field).unwrap_or(&Value::Null), descending)
+        }),
+    }
+}
+
+fn create_uppercase_function() -> Function {
+    map_string(|string| string.to_uppercase())
+}
+
+fn create_lowercase_function() -> Function {
+    map_string(|string| string.to_lowercase())
+}
+
+fn create_trim_function() -> Function {
+    map_string(|string| string.trim().to_owned())
+}
+
+fn create_trim_start_function() -> Function {
+    map_string(|string| string.trim_start().to_owned())
+}
+
+fn create_trim_end_function() -> Function {
+    map_string(|string| string.trim_end().to_owned())
+}
+
+fn create_split_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            let separator = values[1].as_str().ok_or(Error::ExpectedString)?;
+            let parts = match values.get(2) {
+                Some(max) => {
+                    let max = max.as_u64().ok_or(Error::ExpectedNumber)? as usize;
+                    string.splitn(max.saturating_add(1), separator).collect::<Vec<_>>()
+                }
+                None => string.split(separator).collect::<Vec<_>>(),
+            };
+            Ok(to_value(parts))
+        }),
+    }
+}
+
+fn create_join_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let array = values[0].as_array().ok_or(Error::ExpectedArray)?;
+            let separator = match values.get(1) {
+                Some(separator) => separator.as_str().ok_or(Error::ExpectedString)?,
+                None => "",
+            };
+            Ok(to_value(array.iter().map(stringify).collect::<Vec<_>>().join(separator)))
+        }),
+    }
+}
+
+fn create_replace_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(3),
+        compiled: Box::new(|values| {
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            let from = values[1].as_str().ok_or(Error::ExpectedString)?;
+            let to = values[2].as_str().ok_or(Error::ExpectedString)?;
+            Ok(to_value(string.replace(from, to)))
+        }),
+    }
+}
+
+/// Compiled regexes keyed by pattern, shared by the regex builtins of one compiled expression.
+#[cfg(feature = "regex")]
+#[derive(Clone, Default)]
+struct RegexCache(Arc<Mutex<HashMap<String, Regex>>>);
+
+#[cfg(feature = "regex")]
+impl RegexCache {
+    fn get(&self, pattern: &Value) -> Result<Regex, Error> {
+        let pattern = pattern.as_str().ok_or(Error::ExpectedString)?;
+        let mut regexes = self.0.lock().unwrap();
+        if let Some(regex) = regexes.get(pattern) {
+            return Ok(regex.clone());
+        }
+
+        let regex = Regex::new(pattern).map_err(|err| Error::InvalidRegex(err.to_string()))?;
+        regexes.insert(pattern.to_owned(), regex.clone());
+        Ok(regex)
+    }
+}
+
+#[cfg(feature = "regex")]
+fn create_replace_regex_function(regexes: &RegexCache) -> Function {
+    let regexes = regexes.clone();
+    Function {
+        max_args: Some(3),
+        min_args: Some(3),
+        compiled: Box::new(move |values| {
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            let to = values[2].as_str().ok_or(Error::ExpectedString)?;
+            Ok(to_value(regexes.get(&values[1])?.replace_all(string, to)))
+        }),
+    }
+}
+
+#[cfg(feature = "regex")]
+fn create_is_match_function(regexes: &RegexCache) -> Function {
+    let regexes = regexes.clone();
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            Ok(to_value(regexes.get(&values[1])?.is_match(string)))
+        }),
+    }
+}
+
+/// Capture groups of the first match, `nihil` when nothing matches.
+#[cfg(feature = "regex")]
+fn create_captures_function(regexes: &RegexCache) -> Function {
+    let regexes = regexes.clone();
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            match regexes.get(&values[1])?.captures(string) {
+                Some(captures) => {
+                    Ok(to_value(captures.iter()
+                        .skip(1)
+                        .map(|group| group.map(|group| group.as_str()))
+                        .collect::<Vec<_>>()))
+                }
+                None => Ok(Value::Null),
+            }
+        }),
+    }
+}
+
+#[cfg(feature = "regex")]
+fn create_find_all_function(regexes: &RegexCache) -> Function {
+    let regexes = regexes.clone();
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            Ok(to_value(regexes.get(&values[1])?
+                .find_iter(string)
+                .map(|found| found.as_str())
+                .collect::<Vec<_>>()))
+        }),
+    }
+}
+
+fn create_substring_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let chars = values[0].as_str().ok_or(Error::ExpectedString)?.chars().collect::<Vec<_>>();
+            let start = char_index(&values[1], chars.len())?.min(chars.len());
+            let end = match values.get(2) {
+                Some(len) => {
+                    let len = len.as_u64().ok_or(Error::ExpectedNumber)? as usize;
+                    chars.len().min(start.saturating_add(len))
+                }
+                None => chars.len(),
+            };
+            Ok(to_value(chars[start..end].iter().collect::<String>()))
+        }),
+    }
+}
+
+fn create_char_at_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let chars = values[0].as_str().ok_or(Error::ExpectedString)?.chars().collect::<Vec<_>>();
+            let index = char_index(&values[1], chars.len())?;
+            Ok(chars.get(index).map_or(Value::Null, |c| to_value(c.to_string())))
+        }),
+    }
+}
+
+fn create_contains_function() -> Function {
+    predicate(|string, sub| string.contains(sub),
+              |array, item| array.iter().any(|value| equals(value, item)))
+}
+
+fn create_starts_with_function() -> Function {
+    predicate(|string, prefix| string.starts_with(prefix),
+              |array, item| array.first().is_some_and(|value| equals(value, item)))
+}
+
+fn create_ends_with_function() -> Function {
+    predicate(|string, suffix| string.ends_with(suffix),
+              |array, item| array.last().is_some_and(|value| equals(value, item)))
+}
+
+/// Builds a two argument boolean builtin working on either a string and a
+/// substring, or an array and an item.
+fn predicate<S, A>(string: S, array: A) -> Function
+    where S: 'static + Fn(&str, &str) -> bool + Sync + Send,
+          A: 'static + Fn(&[Value], &Value) -> bool + Sync + Send
+{
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| match values[0] {
+            Value::String(ref haystack) => {
+                let needle = values[1].as_str().ok_or(Error::ExpectedString)?;
+                Ok(to_value(string(haystack, needle)))
+            }
+            Value::Array(ref haystack) => Ok(to_value(array(haystack, &values[1]))),
+            Value::Null => Ok(to_value(false)),
+            _ => Err(Error::ExpectedString),
+        }),
+    }
+}
+
+/// Zero-based char or item position of the first (or last) occurrence, -1 when missing.
+fn create_index_of_function(last: bool) -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let index = match values[0] {
+                Value::String(ref haystack) => {
+                    let needle = values[1].as_str().ok_or(Error::ExpectedString)?;
+                    let found = if last { haystack.rfind(needle) } else { haystack.find(needle) };
+                    found.map(|offset| haystack[..offset].chars().count())
+                }
+                Value::Array(ref haystack) => {
+                    let matches = |value: &Value| equals(value, &values[1]);
+                    if last {
+                        haystack.iter().rposition(matches)
+                    } else {
+                        haystack.iter().position(matches)
+                    }
+                }
+                Value::Null => None,
+                _ => return Err(Error::ExpectedString),
+            };
+            Ok(to_value(index.map_or(-1, |index| index as i64)))
+        }),
+    }
+}
+
+/// Pads to `width` chars by repeating `pad` (a space by default), numbers are
+/// stringified first so `isi_kiri(42, 5, '0')` gives `'00042'`.
+fn create_pad_function(left: bool) -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let string = match values[0] {
+                Value::String(_) => stringify(&values[0]),
+                ref number if is_number(number) => stringify(number),
+                _ => return Err(Error::ExpectedString),
+            };
+            let width = check_length(1, values[1].as_u64().ok_or(Error::ExpectedNumber)?)?;
+            let pad = match values.get(2) {
+                Some(pad) => pad.as_str().ok_or(Error::ExpectedString)?,
+                None => " ",
+            };
+            if pad.is_empty() {
+                return Err(Error::Custom("Padding can not be empty.".to_owned()));
+            }
+
+            let fill = pad.chars()
+                .cycle()
+                .take(width.saturating_sub(string.chars().count()))
+                .collect::<String>();
+            if left {
+                Ok(to_value(fill + &string))
+            } else {
+                Ok(to_value(string + &fill))
+            }
+        }),
+    }
+}
+
+/// Integer when the string is one, otherwise float, numbers pass through.
+fn parse_number(value: &Value) -> Result<Value, Error> {
+    match *value {
+        Value::String(ref string) if string.trim().parse::<i64>().is_ok() => value.cast_int(),
+        Value::Number(_) => Ok(value.clone()),
+        _ => value.cast_float(),
+    }
+}
+
+/// The optional second argument is the decimal separator, e.g. `','` for
+/// `'1.234,5'`, the other one of `.` and `,` is then read as grouping.
+fn create_parse_number_function<F>(cast: F) -> Function
+    where F: 'static + Fn(&Value) -> Result<Value, Error> + Sync + Send
+{
+    Function {
+        max_args: Some(2),
+        min_args: Some(1),
+        compiled: Box::new(move |values| {
+            match values.get(1) {
+                Some(separator) => {
+                    parse_with_separator(&values[0], separator.as_str().ok_or(Error::ExpectedString)?, &cast)
+                }
+                None => cast(&values[0]),
+            }
+        }),
+    }
+}
+
+fn parse_with_separator<F>(value: &Value, separator: &str, cast: &F) -> Result<Value, Error>
+    where F: Fn(&Value) -> Result<Value, Error>
+{
+    match *value {
+        Value::String(ref string) => {
+            let grouping = if separator == "," { "." } else { "," };
+            let normalized = string.replace(grouping, "").replace(separator, ".");
+            cast(&to_value(normalized)).map_err(|err| match err {
+                Error::CanNotCast(_, target) => Error::CanNotCast(value.clone(), target),
+                err => err,
+            })
+        }
+        ref value => cast(value),
+    }
+}
+
+/// `angka_lokal('1.234,56')` reads Indonesian formatting, dots group and a comma
+/// separates decimals.
+fn create_local_number_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| parse_with_separator(&values[0], ",", &Value::cast_float)),
+    }
+}
+
+/// Rounds to `decimals` places and groups the integer part by thousands.
+fn format_number(number: f64, decimals: usize, thousands: &str, decimal: &str) -> String {
+    let formatted = format!("{:.*}", decimals, round_half_away(number, decimals as i64).abs());
+    let (integer, fraction) = match formatted.find('.') {
+        Some(index) => (&formatted[..index], &formatted[index + 1..]),
+        None => (&formatted[..], ""),
+    };
+
+    let mut result = String::new();
+    if number < 0.0 && formatted.bytes().any(|byte| byte != b'0' && byte != b'.') {
+        result.push('-');
+    }
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            result.push_str(thousands);
+        }
+        result.push(digit);
+    }
+    if !fraction.is_empty() {
+        result.push_str(decimal);
+        result.push_str(fraction);
+    }
+    result
+}
+
+/// `format_angka(x, decimals, thousands, decimal)`, the separators default to
+/// the Indonesian `.` and `,`.
+fn create_format_number_function() -> Function {
+    Function {
+        max_args: Some(4),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let number = as_f64(&values[0]).ok_or(Error::ExpectedNumber)?;
+            let decimals = match values.get(1) {
+                Some(decimals) => decimals.as_u64().ok_or(Error::ExpectedNumber)?.min(20) as usize,
+                None => 0,
+            };
+            let thousands = values.get(2).map_or(Some("."), Value::as_str).ok_or(Error::ExpectedString)?;
+            let decimal = values.get(3).map_or(Some(","), Value::as_str).ok_or(Error::ExpectedString)?;
+            Ok(to_value(format_number(number, decimals, thousands, decimal)))
+        }),
+    }
+}
+
+/// `rupiah(x, decimals, spaced)`, e.g. `Rp1.500.000` or `-Rp 1.500,50`.
+fn create_rupiah_function() -> Function {
+    Function {
+        max_args: Some(3),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let number = as_f64(&values[0]).ok_or(Error::ExpectedNumber)?;
+            let decimals = match values.get(1) {
+                Some(decimals) => decimals.as_u64().ok_or(Error::ExpectedNumber)?.min(20) as usize,
+                None => 0,
+            };
+            let spaced = match values.get(2) {
+                Some(spaced) => spaced.as_bool().ok_or_else(|| Error::ExpectedBoolean(spaced.clone()))?,
+                None => false,
+            };
+
+            let formatted = format_number(number, decimals, ".", ",");
+            let (sign, amount) = match formatted.strip_prefix('-') {
+                Some(amount) => ("-", amount),
+                None => ("", &formatted[..]),
+            };
+            Ok(to_value(format!("{}Rp{}{}", sign, if spaced { " " } else { "" }, amount)))
+        }),
+    }
+}
+
+const ROMAN: [(u64, &str); 13] = [(1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"),
+                                  (90, "XC"), (50, "L"), (40, "XL"), (10, "X"), (9, "IX"),
+                                  (5, "V"), (4, "IV"), (1, "I")];
+
+fn to_roman(mut number: u64) -> String {
+    let mut roman = String::new();
+    for &(value, numeral) in ROMAN.iter() {
+        while number >= value {
+            roman.push_str(numeral);
+            number -= value;
+        }
+    }
+    roman
+}
+
+/// Only `1..=3999` has a standard notation.
+fn create_to_roman_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| match values[0].as_u64() {
+            Some(number) if (1..4000).contains(&number) => Ok(to_value(to_roman(number))),
+            _ => Err(Error::CanNotCast(values[0].clone(), "romawi".to_owned())),
+        }),
+    }
+}
+
+/// Case insensitive, non canonical numerals like `IIII` are rejected.
+fn create_from_roman_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let roman = values[0].as_str().ok_or(Error::ExpectedString)?.trim().to_uppercase();
+            let mut rest = &roman[..];
+            let mut number = 0;
+            for &(value, numeral) in ROMAN.iter() {
+                while let Some(tail) = rest.strip_prefix(numeral) {
+                    number += value;
+                    rest = tail;
+                }
+            }
+            if rest.is_empty() && number > 0 && to_roman(number) == roman {
+                Ok(to_value(number))
+            } else {
+                Err(Error::CanNotCast(values[0].clone(), "int".to_owned()))
+            }
+        }),
+    }
+}
+
+/// Fills `%s`, `%d`, `%f` or `{}` placeholders with the following arguments in
+/// order, `%%`, `{{` and `}}` are literal.
+fn create_format_function() -> Function {
+    Function {
+        max_args: None,
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let template = values[0].as_str().ok_or(Error::ExpectedString)?;
+            let mut args = values[1..].iter();
+            let mut next_arg = || {
+                args.next().ok_or_else(|| Error::Custom("Not enough arguments for format.".to_owned()))
+            };
+
+            let mut result = String::new();
+            let mut chars = template.chars().peekable();
+            while let Some(c) = chars.next() {
+                match (c, chars.peek().cloned()) {
+                    ('%', Some('%')) | ('{', Some('{')) | ('}', Some('}')) => {
+                        chars.next();
+                        result.push(c);
+                    }
+                    ('%', Some('s')) | ('{', Some('}')) => {
+                        chars.next();
+                        result += &stringify(next_arg()?);
+                    }
+                    ('%', Some('d')) => {
+                        chars.next();
+                        let value = next_arg()?;
+                        let number = as_f64(value).ok_or(Error::ExpectedNumber)?;
+                        if is_integer(value) {
+                            result += &stringify(value);
+                        } else {
+                            result += &(number.trunc() as i64).to_string();
+                        }
+                    }
+                    ('%', Some('f')) => {
+                        chars.next();
+                        result += &as_f64(next_arg()?).ok_or(Error::ExpectedNumber)?.to_string();
+                    }
+                    _ => result.push(c),
+                }
+            }
+            Ok(to_value(result))
+        }),
+    }
+}
+
+/// Resolves an index into a string of `len` chars, negative indexes count from the end.
+fn char_index(index: &Value, len: usize) -> Result<usize, Error> {
+    match index.as_i64() {
+        Some(index) if index < 0 => Ok(len.saturating_sub(index.unsigned_abs() as usize)),
+        Some(index) => Ok(index as usize),
+        None => index.as_u64().map(|index| index as usize).ok_or(Error::ExpectedNumber),
+    }
+}
+
+/// The name `tipe` returns for a value.
+fn type_name(value: &Value) -> &'static str {
+    match *value {
+        Value::Number(_) => "angka",
+        Value::String(_) => "teks",
+        Value::Array(_) => "larik",
+        Value::Object(_) if is_number(value) => "angka",
+        Value::Object(_) => "objek",
+        Value::Bool(_) => "boolean",
+        Value::Null => "nihil",
+    }
+}
+
+fn create_type_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| Ok(to_value(type_name(&values[0])))),
+    }
+}
+
+fn create_is_type_function(name: &'static str) -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| Ok(to_value(type_name(&values[0]) == name))),
+    }
+}
+
+fn create_reverse_string_function() -> Function {
+    map_string(|string| string.chars().rev().collect())
+}
+
+fn create_capitalize_function() -> Function {
+    map_string(|string| {
+        let mut chars = string.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    })
+}
+
+fn create_title_case_function() -> Function {
+    map_string(|string| {
+        let mut title = String::with_capacity(string.len());
+        let mut word_start = true;
+        for c in string.chars() {
+            if word_start {
+                title.extend(c.to_uppercase());
+            } else {
+                title.extend(c.to_lowercase());
+            }
+            word_start = c.is_whitespace();
+        }
+        title
+    })
+}
+
+fn create_slug_function() -> Function {
+    map_string(|string| {
+        let mut slug = String::with_capacity(string.len());
+        let mut dash = false;
+        for c in string.to_lowercase().chars() {
+            let ascii = if c.is_ascii_alphanumeric() {
+                c.to_string()
+            } else {
+                transliterate(c).unwrap_or("").to_owned()
+            };
+
+            if ascii.is_empty() {
+                dash = !slug.is_empty();
+            } else {
+                if dash {
+                    slug.push('-');
+                    dash = false;
+                }
+                slug += &ascii;
+            }
+        }
+        slug
+    })
+}
+
+/// ASCII spelling of common lowercase Latin letters with diacritics.
+fn transliterate(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ł' | 'ľ' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'ś' | 'š' | 'ş' => "s",
+        'ß' => "ss",
+        'ť' | 'ţ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+fn create_compare_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| Ok(ordering(math::compare(&values[0], &values[1])?))),
+    }
+}
+
+/// Compares strings treating digit runs as numbers, so `'item2'` comes before `'item10'`.
+fn create_natural_compare_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let a = values[0].as_str().ok_or(Error::ExpectedString)?;
+            let b = values[1].as_str().ok_or(Error::ExpectedString)?;
+            Ok(ordering(natural_cmp(a, b)))
+        }),
+    }
+}
+
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.chars().peekable(), b.chars().peekable());
+    loop {
+        match (a.peek().cloned(), b.peek().cloned()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = digits(&mut a);
+                let y = digits(&mut b);
+                let order = x.trim_start_matches('0')
+                    .len()
+                    .cmp(&y.trim_start_matches('0').len())
+                    .then_with(|| x.trim_start_matches('0').cmp(y.trim_start_matches('0')))
+                    .then_with(|| x.len().cmp(&y.len()));
+                if order != Ordering::Equal {
+                    return order;
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+fn digits<I: Iterator<Item = char>>(chars: &mut Peekable<I>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.peek().cloned() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        digits.push(c);
+        chars.next();
+    }
+    digits
+}
+
+fn ordering(ordering: Ordering) -> Value {
+    to_value(ordering as i8)
+}
+
+fn create_to_bool_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| Ok(to_value(truthy(&values[0])))),
+    }
+}
+
+fn create_to_string_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| Ok(to_value(stringify(&values[0])))),
+    }
+}
+
+fn create_to_json_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            #[cfg(feature = "bigint")]
+            let value = math::export_big_integers(values[0].clone());
+            #[cfg(not(feature = "bigint"))]
+            let value = &values[0];
+            Ok(to_value(value.to_string()))
+        }),
+    }
+}
+
+fn create_from_json_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let json = values[0].as_str().ok_or(Error::ExpectedString)?;
+            serde_json::from_str(json).map_err(|err| Error::InvalidJson(err.to_string()))
+        }),
+    }
+}
+
+/// Percent-encodes everything except the RFC 3986 unreserved characters.
+fn create_url_encode_function() -> Function {
+    map_string(|string| {
+        let mut encoded = String::with_capacity(string.len());
+        for byte in string.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    encoded.push(byte as char)
+                }
+                _ => encoded += &format!("%{:02X}", byte),
+            }
+        }
+        encoded
+    })
+}
+
+fn create_url_decode_function() -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            let invalid = || Error::InvalidEncoding(format!("malformed percent-encoding: {}", string));
+            let bytes = string.as_bytes();
+            let mut decoded = Vec::with_capacity(bytes.len());
+            let mut index = 0;
+            while index < bytes.len() {
+                if bytes[index] == b'%' {
+                    let hex = string.get(index + 1..index + 3).ok_or_else(invalid)?;
+                    decoded.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+                    index += 3;
+                } else {
+                    decoded.push(bytes[index]);
+                    index += 1;
+                }
+            }
+            String::from_utf8(decoded).map(to_value).map_err(|_| invalid())
+        }),
+    }
+}
+
+/// Builds a builtin decoding a string into bytes that must be valid UTF-8.
+#[cfg(any(feature = "base64", feature = "hex"))]
+fn decode_string<F>(decode: F) -> Function
+    where F: 'static + Fn(&str) -> Result<Vec<u8>, String> + Sync + Send
+{
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| {
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            let bytes = decode(string).map_err(Error::InvalidEncoding)?;
+            String::from_utf8(bytes)
+                .map(to_value)
+                .map_err(|err| Error::InvalidEncoding(err.to_string()))
+        }),
+    }
+}
+
+/// Lowercase hex digest of the UTF-8 bytes of a string.
+#[cfg(feature = "hash")]
+fn create_hash_function<D: Digest>() -> Function {
+    map_string(|string| {
+        D::digest(string.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+    })
+}
+
+/// `hash_bucket(s, n)` maps a string to `0..n` through the first 8 bytes of its
+/// SHA-256, e.g. `hash_bucket(user.id + 'exp1', 100) < 10` for a 10% rollout.
+#[cfg(feature = "hash")]
+fn create_hash_bucket_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            let buckets = values[1].as_u64().ok_or(Error::ExpectedNumber)?;
+            if buckets == 0 {
+                return Err(Error::DivisionByZero);
+            }
+            let digest = sha2::Sha256::digest(string.as_bytes());
+            let mut prefix = [0; 8];
+            prefix.copy_from_slice(&digest[..8]);
+            Ok(to_value(u64::from_be_bytes(prefix) % buckets))
+        }),
+    }
+}
+
+#[cfg(feature = "uuid")]
+fn create_uuid_function() -> Function {
+    Function {
+        max_args: Some(0),
+        min_args: Some(0),
+        compiled: Box::new(|_| Ok(to_value(Uuid::new_v4().to_string()))),
+    }
+}
+
+/// Name based UUID, the namespace is a UUID string or one of `dns`, `url`, `oid`, `x500`.
+#[cfg(feature = "uuid")]
+fn create_uuid_v5_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let namespace = match values[0].as_str().ok_or(Error::ExpectedString)? {
+                "dns" => Uuid::NAMESPACE_DNS,
+                "url" => Uuid::NAMESPACE_URL,
+                "oid" => Uuid::NAMESPACE_OID,
+                "x500" => Uuid::NAMESPACE_X500,
+                namespace => {
+                    Uuid::parse_str(namespace).map_err(|err| Error::Custom(err.to_string()))?
+                }
+            };
+            let name = match values[1] {
+                Value::String(ref name) => name.clone(),
+                ref name => name.to_string(),
+            };
+            Ok(to_value(Uuid::new_v5(&namespace, name.as_bytes()).to_string()))
+        }),
+    }
+}
+
+#[cfg(feature = "chrono")]
+const BULAN: [&str; 12] = ["Januari", "Februari", "Maret", "April", "Mei", "Juni", "Juli",
+                           "Agustus", "September", "Oktober", "November", "Desember"];
+#[cfg(feature = "chrono")]
+const HARI: [&str; 7] = ["Senin", "Selasa", "Rabu", "Kamis", "Jumat", "Sabtu", "Minggu"];
+
+/// Reads RFC 3339 timestamps, `2024-05-17 08:30:00` and `2024-05-17` as UTC,
+/// or epoch milliseconds.
+#[cfg(feature = "chrono")]
+fn parse_datetime(value: &Value) -> Result<DateTime<FixedOffset>, Error> {
+    let invalid = || Error::InvalidDate(stringify(value));
+    match *value {
+        Value::String(ref string) => {
+            let string = string.trim();
+            DateTime::parse_from_rfc3339(string)
+                .or_else(|_| NaiveDateTime::parse_from_str(string, "%Y-%m-%dT%H:%M:%S%.f").map(utc))
+                .or_else(|_| NaiveDateTime::parse_from_str(string, "%Y-%m-%d %H:%M:%S%.f").map(utc))
+                .or_else(|_| NaiveDate::parse_from_str(string, "%Y-%m-%d").map(|date| utc(date.and_hms_opt(0, 0, 0).unwrap())))
+                .map_err(|_| invalid())
+        }
+        Value::Number(_) => {
+            let millis = value.as_i64().ok_or_else(invalid)?;
+            Utc.timestamp_millis_opt(millis).single().map(|time| time.fixed_offset()).ok_or_else(invalid)
+        }
+        _ => Err(Error::ExpectedString),
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn utc(time: NaiveDateTime) -> DateTime<FixedOffset> {
+    time.and_utc().fixed_offset()
+}
+
+/// The same `2024-05-17T08:30:00.000Z` form `sekarang` returns, offsets other than UTC are kept.
+#[cfg(feature = "chrono")]
+fn datetime_value(time: DateTime<FixedOffset>) -> Value {
+    to_value(time.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// `tanggal('2024-05-17')` or `tanggal('17/05/2024', '%d/%m/%Y')`.
+#[cfg(feature = "chrono")]
+fn create_date_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let format = match values.get(1) {
+                Some(format) => format.as_str().ok_or(Error::ExpectedString)?,
+                None => return parse_datetime(&values[0]).map(datetime_value),
+            };
+            let string = values[0].as_str().ok_or(Error::ExpectedString)?;
+            DateTime::parse_from_str(string, format)
+                .or_else(|_| NaiveDateTime::parse_from_str(string, format).map(utc))
+                .or_else(|_| NaiveDate::parse_from_str(string, format).map(|date| utc(date.and_hms_opt(0, 0, 0).unwrap())))
+                .map(datetime_value)
+                .map_err(|_| Error::InvalidDate(string.to_owned()))
+        }),
+    }
+}
+
+/// `tambah_hari(t, 7)` style builtins, a negative amount goes back in time.
+#[cfg(feature = "chrono")]
+fn create_add_date_function<F>(add: F) -> Function
+    where F: 'static + Fn(DateTime<FixedOffset>, i64) -> Option<DateTime<FixedOffset>> + Sync + Send
+{
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let time = parse_datetime(&values[0])?;
+            let amount = values[1].as_i64().ok_or(Error::ExpectedNumber)?;
+            add(time, amount).map(datetime_value).ok_or(Error::Overflow)
+        }),
+    }
+}
+
+/// `selisih_hari(a, b)` style builtins, whole units of `a - b` truncated toward zero.
+#[cfg(feature = "chrono")]
+fn create_date_diff_function(unit: fn(&TimeDelta) -> i64) -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(move |values| {
+            let a = parse_datetime(&values[0])?;
+            let b = parse_datetime(&values[1])?;
+            Ok(to_value(unit(&a.signed_duration_since(b))))
+        }),
+    }
+}
+
+/// Components are read in the timestamp's own offset, not converted to UTC.
+#[cfg(feature = "chrono")]
+fn create_date_part_function(part: fn(&DateTime<FixedOffset>) -> i64) -> Function {
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| Ok(to_value(part(&parse_datetime(&values[0])?)))),
+    }
+}
+
+/// `hari_minggu(t)` is `1` for Senin up to `7` for Minggu, `hari_minggu(t, benar)` the name.
+#[cfg(feature = "chrono")]
+fn create_weekday_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(1),
+        compiled: Box::new(|values| {
+            let weekday = parse_datetime(&values[0])?.weekday();
+            match values.get(1) {
+                Some(&Value::Bool(true)) => Ok(to_value(HARI[weekday.num_days_from_monday() as usize])),
+                None | Some(&Value::Bool(false)) => Ok(to_value(weekday.number_from_monday())),
+                Some(value) => Err(Error::ExpectedBoolean(value.clone())),
+            }
+        }),
+    }
+}
+
+/// `ke_zona(t, 'Asia/Jakarta')` is the same instant with the zone's offset at that time.
+#[cfg(feature = "chrono-tz")]
+fn create_to_zone_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            let time = parse_datetime(&values[0])?;
+            let name = values[1].as_str().ok_or(Error::ExpectedString)?;
+            let zone = name.parse::<Tz>().map_err(|_| Error::Custom(format!("Unknown time zone: {}", name)))?;
+            Ok(datetime_value(time.with_timezone(&zone).fixed_offset()))
+        }),
+    }
+}
+
+/// Swaps `%A`, `%a`, `%B` and `%b` for Indonesian day and month names.
+#[cfg(feature = "chrono")]
+fn indonesian_names(format: &str, time: &DateTime<FixedOffset>) -> String {
+    let day = HARI[time.weekday().num_days_from_monday() as usize];
+    let month = BULAN[time.month0() as usize];
+    let mut localized = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('A') => localized += day,
+            Some('a') => localized += &day[..3],
+            Some('B') => localized += month,
+            Some('b') => localized += &month[..3],
+            Some(other) => {
+                localized.push('%');
+                localized.push(other);
+            }
+            None => localized.push('%'),
+        }
+    }
+    localized
+}
+
+/// `format_tanggal(t, '%d %B %Y')`, the Indonesian variant defaults to `17 Mei 2024`.
+#[cfg(feature = "chrono")]
+fn create_format_date_function(indonesian: bool) -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(if indonesian { 1 } else { 2 }),
+        compiled: Box::new(move |values| {
+            let time = parse_datetime(&values[0])?;
+            let format = match values.get(1) {
+                Some(format) => format.as_str().ok_or(Error::ExpectedString)?,
+                None => "%-d %B %Y",
+            };
+            let format = if indonesian { indonesian_names(format, &time) } else { format.to_owned() };
+            if StrftimeItems::new(&format).any(|item| item == Item::Error) {
+                return Err(Error::Custom(format!("Invalid date format: {}", format)));
+            }
+            Ok(to_value(time.format(&format).to_string()))
+        }),
+    }
+}
+
+fn create_repeat_function() -> Function {
+    Function {
+        max_args: Some(2),
+        min_args: Some(2),
+        compiled: Box::new(|values| {
+            if !values[0].is_string() && !values[0].is_array() {
+                return Err(Error::ExpectedString);
+            }
+            values[0].mul(&values[1])
+        }),
+    }
+}
+
+fn map_string<F>(map: F) -> Function
+    where F: 'static + Fn(&str) -> String + Sync + Send
+{
+    Function {
+        max_args: Some(1),
+        min_args: Some(1),
+        compiled: Box::new(move |values| match *values.first().unwrap() {
+            Value::String(ref string) => Ok(to_value(map(string))),
+            Value::Null => Ok(Value::Null),
+            _ => Err(Error::ExpectedString),
+        }),
+    }
+}
@@ -2,60 +2,113 @@ use crate::*;
 use serde_json::Value;
 use Error;
 use to_value;
+use std::cmp::Ordering;
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+#[cfg(feature = "decimal")]
+use std::str::FromStr;
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+#[cfg(feature = "bigint")]
+use std::convert::TryFrom;
+#[cfg(feature = "bigint")]
+use std::collections::hash_map::RandomState;
+#[cfg(feature = "bigint")]
+use std::hash::{BuildHasher, Hasher};
+#[cfg(feature = "bigint")]
+use std::sync::OnceLock;
 
 pub trait Math {
     fn add(&self, value: &Value) -> Result<Value, Error>;
     fn mul(&self, value: &Value) -> Result<Value, Error>;
     fn sub(&self, value: &Value) -> Result<Value, Error>;
     fn div(&self, value: &Value) -> Result<Value, Error>;
+    fn int_div(&self, value: &Value) -> Result<Value, Error>;
     fn rem(&self, value: &Value) -> Result<Value, Error>;
+    fn pow(&self, value: &Value) -> Result<Value, Error>;
+    fn neg(&self) -> Result<Value, Error>;
+    fn cast_int(&self) -> Result<Value, Error>;
+    fn cast_float(&self) -> Result<Value, Error>;
+    fn cast_str(&self) -> Result<Value, Error>;
+    fn bit_and(&self, value: &Value) -> Result<Value, Error>;
+    fn bit_or(&self, value: &Value) -> Result<Value, Error>;
+    fn bit_xor(&self, value: &Value) -> Result<Value, Error>;
+    fn shl(&self, value: &Value) -> Result<Value, Error>;
+    fn shr(&self, value: &Value) -> Result<Value, Error>;
     fn eq(&self, value: &Value) -> Result<Value, Error>;
     fn ne(&self, value: &Value) -> Result<Value, Error>;
     fn gt(&self, value: &Value) -> Result<Value, Error>;
     fn lt(&self, value: &Value) -> Result<Value, Error>;
     fn ge(&self, value: &Value) -> Result<Value, Error>;
     fn le(&self, value: &Value) -> Result<Value, Error>;
+    fn is_in(&self, value: &Value) -> Result<Value, Error>;
     fn and(&self, value: &Value) -> Result<Value, Error>;
     fn or(&self, value: &Value) -> Result<Value, Error>;
 }
 
 impl Math for Value {
     fn add(&self, value: &Value) -> Result<Value, Error> {
-        if self.is_number() && value.is_number() {
+        if self.is_numeric() && value.is_numeric() {
             if self.is_f64() || value.is_f64() {
-                Ok(to_value(self.get_f64() + value.get_f64()))
-            } else if self.is_i64() || value.is_i64() {
-                Ok(to_value(self.get_i64() + value.get_i64()))
+                Ok(to_value(float_arithmetic(self.get_f64(), value.get_f64(), Operation::Add)))
             } else {
-                Ok(to_value(self.get_u64() + value.get_u64()))
+                integer_arithmetic(self, value, Overflow::Checked, Operation::Add)
             }
         } else if self.is_string() && value.is_string() {
             Ok(to_value(self.get_string() + value.get_str()))
+        } else if let Value::Array(ref array) = *self {
+            let mut array = array.clone();
+            match *value {
+                Value::Array(ref other) => array.extend(other.iter().cloned()),
+                _ => array.push(value.clone()),
+            }
+            Ok(Value::Array(array))
+        } else if self.is_object() && value.is_object() && !self.is_numeric() && !value.is_numeric() {
+            let mut object = self.as_object().unwrap().clone();
+            for (key, item) in value.as_object().unwrap() {
+                object.insert(key.clone(), item.clone());
+            }
+            Ok(Value::Object(object))
         } else {
             Err(Error::UnsupportedTypes(self.format(), value.format()))
         }
     }
 
     fn mul(&self, value: &Value) -> Result<Value, Error> {
-        if self.is_number() && value.is_number() {
+        if self.is_numeric() && value.is_numeric() {
             if self.is_f64() || value.is_f64() {
-                Ok(to_value(self.get_f64() * value.get_f64()))
-            } else if self.is_i64() || value.is_i64() {
-                Ok(to_value(self.get_i64() * value.get_i64()))
+                Ok(to_value(float_arithmetic(self.get_f64(), value.get_f64(), Operation::Mul)))
             } else {
-                Ok(to_value(self.get_u64() * value.get_u64()))
+                integer_arithmetic(self, value, Overflow::Checked, Operation::Mul)
+            }
+        } else if value.is_u64() {
+            match *self {
+                Value::String(ref string) => {
+                    check_length(string.len(), value.get_u64())?;
+                    Ok(to_value(string.repeat(value.get_u64() as usize)))
+                }
+                Value::Array(ref array) => {
+                    let mut repeated = Vec::with_capacity(check_length(array.len(), value.get_u64())?);
+                    for _ in 0..value.get_u64() {
+                        repeated.extend(array.iter().cloned());
+                    }
+                    Ok(Value::Array(repeated))
+                }
+                _ => Err(Error::UnsupportedTypes(self.format(), value.format())),
             }
+        } else if self.is_u64() && (value.is_string() || value.is_array()) {
+            value.mul(self)
         } else {
             Err(Error::UnsupportedTypes(self.format(), value.format()))
         }
     }
 
     fn sub(&self, value: &Value) -> Result<Value, Error> {
-        if self.is_number() && value.is_number() {
+        if self.is_numeric() && value.is_numeric() {
             if self.is_f64() || value.is_f64() {
-                Ok(to_value(self.get_f64() - value.get_f64()))
+                Ok(to_value(float_arithmetic(self.get_f64(), value.get_f64(), Operation::Sub)))
             } else {
-                Ok(to_value(self.get_i64() - value.get_i64()))
+                integer_arithmetic(self, value, Overflow::Checked, Operation::Sub)
             }
         } else {
             Err(Error::UnsupportedTypes(self.format(), value.format()))
@@ -63,84 +116,237 @@ impl Math for Value {
     }
 
     fn div(&self, value: &Value) -> Result<Value, Error> {
-        if self.is_number() && value.is_number() {
-            Ok(to_value(self.get_f64() / value.get_f64()))
+        if is_zero(value) {
+            Err(Error::DivisionByZero)
+        } else if self.is_numeric() && value.is_numeric() {
+            Ok(to_value(float_arithmetic(self.get_f64(), value.get_f64(), Operation::Div)))
+        } else {
+            Err(Error::UnsupportedTypes(self.format(), value.format()))
+        }
+    }
+
+    fn int_div(&self, value: &Value) -> Result<Value, Error> {
+        if is_zero(value) {
+            Err(Error::DivisionByZero)
+        } else if self.is_numeric() && value.is_numeric() {
+            if self.is_f64() || value.is_f64() {
+                Ok(to_value((self.get_f64() / value.get_f64()).trunc()))
+            } else {
+                integer_arithmetic(self, value, Overflow::Checked, Operation::Div)
+            }
         } else {
             Err(Error::UnsupportedTypes(self.format(), value.format()))
         }
     }
 
     fn rem(&self, value: &Value) -> Result<Value, Error> {
-        if self.is_number() && value.is_number() {
+        if is_zero(value) {
+            Err(Error::DivisionByZero)
+        } else if self.is_numeric() && value.is_numeric() {
             if self.is_f64() || value.is_f64() {
-                Ok(to_value(self.get_f64() % value.get_f64()))
-            } else if self.is_i64() || value.is_i64() {
-                Ok(to_value(self.get_i64() % value.get_i64()))
+                Ok(to_value(float_arithmetic(self.get_f64(), value.get_f64(), Operation::Rem)))
             } else {
-                Ok(to_value(self.get_u64() % value.get_u64()))
+                integer_arithmetic(self, value, Overflow::Checked, Operation::Rem)
             }
         } else {
             Err(Error::UnsupportedTypes(self.format(), value.format()))
         }
     }
 
-    fn eq(&self, value: &Value) -> Result<Value, Error> {
-        if self.is_number() && value.is_number() {
-            Ok(to_value(self.get_f64() == value.get_f64()))
+    fn pow(&self, value: &Value) -> Result<Value, Error> {
+        if self.is_numeric() && value.is_numeric() {
+            if is_integer(self) && is_exponent(value) {
+                return integer_arithmetic(self, value, Overflow::Checked, Operation::Pow);
+            }
+            let result = self.get_f64().powf(value.get_f64());
+            if result.is_finite() {
+                Ok(to_value(result))
+            } else {
+                Err(Error::NotFinite)
+            }
         } else {
-            Ok(to_value(self == value))
+            Err(Error::UnsupportedTypes(self.format(), value.format()))
         }
     }
 
-    fn ne(&self, value: &Value) -> Result<Value, Error> {
-        if self.is_number() && value.is_number() {
-            Ok(to_value(self.get_f64() != value.get_f64()))
+    fn neg(&self) -> Result<Value, Error> {
+        #[cfg(feature = "bigint")]
+        {
+            if let Some(number) = big_integer(self) {
+                return Ok(big_value(-number));
+            }
+        }
+
+        if integer(self).is_some() {
+            integer_arithmetic(&to_value(0), self, Overflow::Float, Operation::Sub)
+        } else if self.is_number() {
+            Ok(to_value(-self.get_f64()))
         } else {
-            Ok(to_value(self != value))
+            Err(Error::ExpectedNumber)
+        }
+    }
+
+    fn cast_int(&self) -> Result<Value, Error> {
+        match *self {
+            Value::Number(_) if integer(self).is_some() => Ok(self.clone()),
+            Value::Number(_) => {
+                truncate(self.get_f64()).map(to_value).ok_or_else(|| Error::CanNotCast(self.clone(), "int".to_owned()))
+            }
+            #[cfg(feature = "bigint")]
+            Value::Object(_) if self.is_numeric() => Ok(self.clone()),
+            Value::String(ref string) => {
+                let string = string.trim();
+                #[cfg(feature = "bigint")]
+                {
+                    if let Ok(number) = string.parse::<BigInt>() {
+                        return Ok(big_value(number));
+                    }
+                }
+                match (string.parse::<i64>(), string.parse::<f64>().ok().and_then(truncate)) {
+                    (Ok(number), _) => Ok(to_value(number)),
+                    (_, Some(number)) => Ok(to_value(number)),
+                    _ => Err(Error::CanNotCast(self.clone(), "int".to_owned())),
+                }
+            }
+            Value::Bool(boolean) => Ok(to_value(boolean as i64)),
+            Value::Null => Ok(Value::Null),
+            _ => Err(Error::CanNotCast(self.clone(), "int".to_owned())),
+        }
+    }
+
+    fn cast_float(&self) -> Result<Value, Error> {
+        match *self {
+            Value::Number(_) => Ok(to_value(self.get_f64())),
+            #[cfg(feature = "bigint")]
+            Value::Object(_) if self.is_numeric() => Ok(to_value(self.get_f64())),
+            Value::String(ref string) => {
+                match string.trim().parse::<f64>() {
+                    Ok(number) if number.is_finite() => Ok(to_value(number)),
+                    _ => Err(Error::CanNotCast(self.clone(), "float".to_owned())),
+                }
+            }
+            Value::Bool(boolean) => Ok(to_value(boolean as i64 as f64)),
+            Value::Null => Ok(Value::Null),
+            _ => Err(Error::CanNotCast(self.clone(), "float".to_owned())),
+        }
+    }
+
+    fn cast_str(&self) -> Result<Value, Error> {
+        match *self {
+            Value::Null => Ok(Value::Null),
+            _ => Ok(to_value(stringify(self))),
+        }
+    }
+
+    fn bit_and(&self, value: &Value) -> Result<Value, Error> {
+        #[cfg(feature = "bigint")]
+        {
+            if let Some((a, b)) = big_integers(self, value) {
+                return Ok(big_value(a & b));
+            }
+        }
+        let (a, b) = integers(self, value)?;
+        Ok(to_value(a & b))
+    }
+
+    fn bit_or(&self, value: &Value) -> Result<Value, Error> {
+        #[cfg(feature = "bigint")]
+        {
+            if let Some((a, b)) = big_integers(self, value) {
+                return Ok(big_value(a | b));
+            }
+        }
+        let (a, b) = integers(self, value)?;
+        Ok(to_value(a | b))
+    }
+
+    fn bit_xor(&self, value: &Value) -> Result<Value, Error> {
+        #[cfg(feature = "bigint")]
+        {
+            if let Some((a, b)) = big_integers(self, value) {
+                return Ok(big_value(a ^ b));
+            }
         }
+        let (a, b) = integers(self, value)?;
+        Ok(to_value(a ^ b))
+    }
+
+    fn shl(&self, value: &Value) -> Result<Value, Error> {
+        #[cfg(feature = "bigint")]
+        {
+            if let (Some(a), true) = (big_integer(self).filter(|_| !self.is_i64()), value.is_i64()) {
+                return Ok(big_value(a << shift_amount(value.get_i64())?));
+            }
+        }
+        let (a, b) = integers(self, value)?;
+        Ok(to_value(a << shift_amount(b)?))
+    }
+
+    fn shr(&self, value: &Value) -> Result<Value, Error> {
+        #[cfg(feature = "bigint")]
+        {
+            if let (Some(a), true) = (big_integer(self).filter(|_| !self.is_i64()), value.is_i64()) {
+                return Ok(big_value(a >> shift_amount(value.get_i64())?));
+            }
+        }
+        let (a, b) = integers(self, value)?;
+        Ok(to_value(a >> shift_amount(b)?))
+    }
+
+    fn eq(&self, value: &Value) -> Result<Value, Error> {
+        Ok(to_value(equals(self, value)))
+    }
+
+    fn ne(&self, value: &Value) -> Result<Value, Error> {
+        Ok(to_value(!equals(self, value)))
     }
 
     fn gt(&self, value: &Value) -> Result<Value, Error> {
         if self.is_null() || value.is_null() {
             return Ok(to_value(false));
         }
-        if self.is_number() && value.is_number() {
-            Ok(to_value(self.get_f64() > value.get_f64()))
-        } else {
-            Err(Error::UnsupportedTypes(self.format(), value.format()))
-        }
+        Ok(to_value(compare(self, value)? == Ordering::Greater))
     }
 
     fn lt(&self, value: &Value) -> Result<Value, Error> {
         if self.is_null() || value.is_null() {
             return Ok(to_value(false));
         }
-        if self.is_number() && value.is_number() {
-            Ok(to_value(self.get_f64() < value.get_f64()))
-        } else {
-            Err(Error::UnsupportedTypes(self.format(), value.format()))
-        }
+        Ok(to_value(compare(self, value)? == Ordering::Less))
     }
 
     fn ge(&self, value: &Value) -> Result<Value, Error> {
         if self.is_null() || value.is_null() {
             return Ok(to_value(false));
         }
-        if self.is_number() && value.is_number() {
-            Ok(to_value(self.get_f64() >= value.get_f64()))
-        } else {
-            Err(Error::UnsupportedTypes(self.format(), value.format()))
-        }
+        Ok(to_value(compare(self, value)? != Ordering::Less))
     }
 
     fn le(&self, value: &Value) -> Result<Value, Error> {
         if self.is_null() || value.is_null() {
             return Ok(to_value(false));
         }
-        if self.is_number() && value.is_number() {
-            Ok(to_value(self.get_f64() <= value.get_f64()))
-        } else {
-            Err(Error::UnsupportedTypes(self.format(), value.format()))
+        Ok(to_value(compare(self, value)? != Ordering::Greater))
+    }
+
+    fn is_in(&self, value: &Value) -> Result<Value, Error> {
+        match *value {
+            Value::Array(ref array) => {
+                for item in array {
+                    if Math::eq(self, item)? == to_value(true) {
+                        return Ok(to_value(true));
+                    }
+                }
+                Ok(to_value(false))
+            }
+            Value::Object(ref object) if self.is_string() => {
+                Ok(to_value(object.contains_key(self.get_str())))
+            }
+            Value::String(ref string) if self.is_string() => {
+                Ok(to_value(string.contains(self.get_str())))
+            }
+            Value::Null => Ok(to_value(false)),
+            _ => Err(Error::UnsupportedTypes(self.format(), value.format())),
         }
     }
 
@@ -161,8 +367,390 @@ impl Math for Value {
     }
 }
 
+/// Most bytes of a string or items of an array that repeating, padding or a
+/// range may build, larger results are `Error::TooLarge` instead of an allocation abort.
+pub const MAX_LENGTH: usize = 1 << 24;
+
+/// `len * count` when it is within `MAX_LENGTH`.
+pub fn check_length(len: usize, count: u64) -> Result<usize, Error> {
+    match (len as u64).checked_mul(count) {
+        Some(total) if total <= MAX_LENGTH as u64 => Ok(total as usize),
+        _ => Err(Error::TooLarge),
+    }
+}
+
+/// `+` with integer overflow handled by the configured mode.
+pub fn add(a: &Value, b: &Value, overflow: Overflow) -> Result<Value, Error> {
+    if integer(a).is_some() && integer(b).is_some() {
+        integer_arithmetic(a, b, overflow, Operation::Add)
+    } else {
+        a.add(b)
+    }
+}
+
+/// `-` with integer overflow handled by the configured mode.
+pub fn subtract(a: &Value, b: &Value, overflow: Overflow) -> Result<Value, Error> {
+    if integer(a).is_some() && integer(b).is_some() {
+        integer_arithmetic(a, b, overflow, Operation::Sub)
+    } else {
+        a.sub(b)
+    }
+}
+
+/// `*` with integer overflow handled by the configured mode.
+pub fn multiply(a: &Value, b: &Value, overflow: Overflow) -> Result<Value, Error> {
+    if integer(a).is_some() && integer(b).is_some() {
+        integer_arithmetic(a, b, overflow, Operation::Mul)
+    } else {
+        a.mul(b)
+    }
+}
+
+/// `**` with integer overflow handled by the configured mode, negative or
+/// fractional exponents give floats.
+pub fn power(a: &Value, b: &Value, overflow: Overflow) -> Result<Value, Error> {
+    if integer(a).is_some() && is_exponent(b) {
+        integer_arithmetic(a, b, overflow, Operation::Pow)
+    } else {
+        a.pow(b)
+    }
+}
+
+/// Unary `-` with integer overflow handled by the configured mode.
+pub fn negate(a: &Value, overflow: Overflow) -> Result<Value, Error> {
+    if integer(a).is_some() {
+        integer_arithmetic(&to_value(0), a, overflow, Operation::Sub)
+    } else {
+        a.neg()
+    }
+}
+
+/// `//` with integer overflow handled by the configured mode, only
+/// `-9223372036854775808 // -1` can overflow.
+pub fn int_divide(a: &Value, b: &Value, overflow: Overflow) -> Result<Value, Error> {
+    if integer(a).is_some() && integer(b).is_some() && !is_zero(b) {
+        integer_arithmetic(a, b, overflow, Operation::Div)
+    } else {
+        a.int_div(b)
+    }
+}
+
+/// Results must fit in i64, or in u64 when an operand is already above
+/// `i64::MAX`, anything outside is an overflow. `Div` truncates.
+#[cfg(not(feature = "bigint"))]
+fn integer_arithmetic(a: &Value, b: &Value, overflow: Overflow, operation: Operation)
+                      -> Result<Value, Error> {
+    let (x, y) = (integer(a).unwrap(), integer(b).unwrap());
+    let min = i64::MIN as i128;
+    let max = if x > i64::MAX as i128 || y > i64::MAX as i128 {
+        u64::MAX as i128
+    } else {
+        i64::MAX as i128
+    };
+
+    let result = match operation {
+        Operation::Add => x.checked_add(y),
+        Operation::Sub => x.checked_sub(y),
+        Operation::Mul => x.checked_mul(y),
+        Operation::Div => x.checked_div(y),
+        Operation::Rem => x.checked_rem(y),
+        Operation::Pow => x.checked_pow(y as u32),
+    };
+    match result {
+        Some(result) if result >= min && result <= max => Ok(to_value(result)),
+        result => match overflow {
+            Overflow::Checked => Err(Error::Overflow),
+            Overflow::Saturating => {
+                let positive = result.map(|result| result > max).unwrap_or(match operation {
+                    Operation::Pow => x >= 0 || y % 2 == 0,
+                    _ => (x < 0) == (y < 0),
+                });
+                Ok(to_value(if positive { max } else { min }))
+            }
+            Overflow::Float => {
+                let result = float_arithmetic(x as f64, y as f64, operation);
+                if result.is_finite() {
+                    Ok(to_value(result))
+                } else {
+                    Err(Error::NotFinite)
+                }
+            }
+        },
+    }
+}
+
+/// With the `bigint` feature integers are exact at any size, so the overflow
+/// mode never applies.
+#[cfg(feature = "bigint")]
+fn integer_arithmetic(a: &Value, b: &Value, _overflow: Overflow, operation: Operation)
+                      -> Result<Value, Error> {
+    let (x, y) = (big_integer(a).unwrap(), big_integer(b).unwrap());
+    Ok(big_value(match operation {
+        Operation::Add => x + y,
+        Operation::Sub => x - y,
+        Operation::Mul => x * y,
+        Operation::Div => x / y,
+        Operation::Rem => x % y,
+        Operation::Pow => {
+            let exponent = u32::try_from(&y).unwrap();
+            check_length(x.bits() as usize, exponent as u64)?;
+            x.pow(exponent)
+        }
+    }))
+}
+
+/// Key of the one-field object that carries an integer outside the i64 and u64
+/// range while evaluating, serde_json numbers cannot hold it. It is random per
+/// process so context data cannot forge one, and never leaves the evaluator,
+/// see `export_big_integers`.
+#[cfg(feature = "bigint")]
+fn big_integer_key() -> &'static str {
+    static KEY: OnceLock<String> = OnceLock::new();
+    KEY.get_or_init(|| format!("$bigint:{:016x}", RandomState::new().build_hasher().finish()))
+}
+
+/// Any integer, a plain number or a big one made by `big_value`.
+#[cfg(feature = "bigint")]
+pub fn big_integer(value: &Value) -> Option<BigInt> {
+    match *value {
+        Value::Number(ref number) if !number.is_f64() => number.to_string().parse().ok(),
+        Value::Object(ref object) if object.len() == 1 => {
+            object.get(big_integer_key()).and_then(Value::as_str).and_then(|digits| digits.parse().ok())
+        }
+        _ => None,
+    }
+}
+
+/// A plain number when it fits in i64 or u64, otherwise the evaluator's own
+/// big integer value.
+#[cfg(feature = "bigint")]
+pub fn big_value(number: BigInt) -> Value {
+    let digits = number.to_string();
+    match digits.parse::<serde_json::Number>() {
+        Ok(number) if !number.is_f64() => Value::Number(number),
+        _ => {
+            let mut object = serde_json::Map::new();
+            object.insert(big_integer_key().to_owned(), to_value(digits));
+            Value::Object(object)
+        }
+    }
+}
+
+/// Replaces big integers, also nested ones, by their digits as a string. Results
+/// and arguments of host functions go through it, `int` reads the digits back.
+#[cfg(feature = "bigint")]
+pub fn export_big_integers(value: Value) -> Value {
+    match value {
+        Value::Object(_) if value.is_numeric() => to_value(stringify(&value)),
+        Value::Object(object) => {
+            Value::Object(object.into_iter().map(|(key, item)| (key, export_big_integers(item))).collect())
+        }
+        Value::Array(array) => Value::Array(array.into_iter().map(export_big_integers).collect()),
+        value => value,
+    }
+}
+
+/// Both operands as big integers when at least one is outside i64.
+#[cfg(feature = "bigint")]
+fn big_integers(a: &Value, b: &Value) -> Option<(BigInt, BigInt)> {
+    if a.is_i64() && b.is_i64() {
+        return None;
+    }
+    Some((big_integer(a)?, big_integer(b)?))
+}
+
+/// A number, or with the `bigint` feature also a big integer.
+pub fn is_number(value: &Value) -> bool {
+    value.is_numeric()
+}
+
+/// An object that is not a big integer.
+pub fn as_object(value: &Value) -> Option<&serde_json::Map<String, Value>> {
+    if value.is_numeric() {
+        None
+    } else {
+        value.as_object()
+    }
+}
+
+/// Any number as f64, big integers rounded to the nearest.
+pub fn as_f64(value: &Value) -> Option<f64> {
+    if value.is_numeric() {
+        Some(value.get_f64())
+    } else {
+        None
+    }
+}
+
+/// Divides following the configured semantics for integer operands, any
+/// float operand always gives float division.
+pub fn divide(a: &Value, b: &Value, division: Division, overflow: Overflow) -> Result<Value, Error> {
+    if !is_integer(a) || !is_integer(b) {
+        return a.div(b);
+    }
+
+    match division {
+        Division::Float => a.div(b),
+        Division::Truncate => int_divide(a, b, overflow),
+        Division::Exact => {
+            if a.rem(b)? == to_value(0) {
+                int_divide(a, b, overflow)
+            } else {
+                Err(Error::InexactDivision(a.clone(), b.clone()))
+            }
+        }
+    }
+}
+
+/// Orders two numbers or two strings. Integers are compared exactly, any other
+/// pair of numbers is compared as floats, so `1 == 1.0` and `2.5 > 2` hold.
+pub fn compare(a: &Value, b: &Value) -> Result<Ordering, Error> {
+    if a.is_numeric() && b.is_numeric() {
+        match (integer(a), integer(b)) {
+            (Some(a), Some(b)) => Ok(a.cmp(&b)),
+            #[cfg(feature = "bigint")]
+            _ if !a.is_f64() && !b.is_f64() => Ok(big_integer(a).cmp(&big_integer(b))),
+            _ => Ok(a.get_f64().partial_cmp(&b.get_f64()).unwrap_or(Ordering::Equal)),
+        }
+    } else if a.is_string() && b.is_string() {
+        Ok(a.get_str().cmp(b.get_str()))
+    } else {
+        Err(Error::UnsupportedTypes(a.format(), b.format()))
+    }
+}
+
+/// Text of a value for `str`, `ke_teks`, templates, `gabung` and `format`. Strings
+/// as is, numbers without exponent, anything else as compact JSON, so `true`, `false` and `null`.
+pub fn stringify(value: &Value) -> String {
+    match *value {
+        Value::String(ref string) => string.clone(),
+        Value::Number(ref number) if number.is_f64() => number.as_f64().unwrap().to_string(),
+        #[cfg(feature = "bigint")]
+        Value::Object(_) if value.is_numeric() => big_integer(value).unwrap().to_string(),
+        _ => value.to_string(),
+    }
+}
+
+/// `ke_bool` coercion, null, `0`, `''`, `[]` and `{}` are false.
+pub fn truthy(value: &Value) -> bool {
+    match *value {
+        Value::Null => false,
+        Value::Bool(boolean) => boolean,
+        Value::Number(ref number) => number.as_f64().map(|number| number != 0.0).unwrap_or(true),
+        Value::String(ref string) => !string.is_empty(),
+        Value::Array(ref array) => !array.is_empty(),
+        Value::Object(ref object) => !object.is_empty(),
+    }
+}
+
+/// Structural equality, recursing into arrays and objects so that nested
+/// numbers are compared the same way as top level ones.
+pub fn equals(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        _ if a.is_numeric() && b.is_numeric() => compare(a, b) == Ok(Ordering::Equal),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| equals(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len() &&
+            a.iter().all(|(key, a)| b.get(key).map(|b| equals(a, b)).unwrap_or(false))
+        }
+        _ => a == b,
+    }
+}
+
+enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+}
+
+/// With the `decimal` feature `+`, `-`, `*`, `/` and `%` on floats are computed
+/// with `rust_decimal` on the shortest decimal form of each operand, and the
+/// result is rounded once to the nearest f64. A single operation on decimal
+/// literals is exact, so `0.1 + 0.2 == 0.3` holds. Values are still f64 between
+/// operations, so a rounded quotient stays rounded (`1.0 / 3 * 3` is
+/// `0.9999999999999999`). `**` and the math and statistics builtins use f64.
+/// Out of the `rust_decimal` range it falls back to f64.
+fn float_arithmetic(a: f64, b: f64, operator: Operation) -> f64 {
+    #[cfg(feature = "decimal")]
+    {
+        let decimal = |number: f64| Decimal::from_str(&number.to_string()).ok();
+        if let (Some(x), Some(y)) = (decimal(a), decimal(b)) {
+            let result = match operator {
+                Operation::Add => x.checked_add(y),
+                Operation::Sub => x.checked_sub(y),
+                Operation::Mul => x.checked_mul(y),
+                Operation::Div => x.checked_div(y),
+                Operation::Rem => x.checked_rem(y),
+                Operation::Pow => None,
+            };
+            // `to_f64` rounds through the mantissa, the decimal string is the nearest f64
+            if let Some(result) = result.and_then(|result| result.to_string().parse::<f64>().ok()) {
+                return result;
+            }
+        }
+    }
+
+    match operator {
+        Operation::Add => a + b,
+        Operation::Sub => a - b,
+        Operation::Mul => a * b,
+        Operation::Div => a / b,
+        Operation::Rem => a % b,
+        Operation::Pow => a.powf(b),
+    }
+}
+
+fn integer(value: &Value) -> Option<i128> {
+    value.as_i64().map(i128::from).or_else(|| value.as_u64().map(i128::from))
+}
+
+/// The integer part when it fits in i64, `as` would clamp `1e30` silently.
+fn truncate(number: f64) -> Option<i64> {
+    let number = number.trunc();
+    if number >= i64::MIN as f64 && number < i64::MAX as f64 {
+        Some(number as i64)
+    } else {
+        None
+    }
+}
+
+/// An integer number, with the `bigint` feature also a big one.
+pub fn is_integer(value: &Value) -> bool {
+    integer(value).is_some() || !value.is_number() && value.is_numeric()
+}
+
+/// Exponents an integer `**` handles, others go through `powf`.
+fn is_exponent(value: &Value) -> bool {
+    value.as_u64().map(|exponent| exponent <= u32::MAX as u64).unwrap_or(false)
+}
+
+fn is_zero(value: &Value) -> bool {
+    value.as_f64() == Some(0.0)
+}
+
+fn integers(a: &Value, b: &Value) -> Result<(i64, i64), Error> {
+    if a.is_i64() && b.is_i64() {
+        Ok((a.get_i64(), b.get_i64()))
+    } else {
+        Err(Error::ExpectedNumber)
+    }
+}
+
+fn shift_amount(amount: i64) -> Result<i64, Error> {
+    if (0..64).contains(&amount) {
+        Ok(amount)
+    } else {
+        Err(Error::Custom(format!("Invalid shift amount: {}", amount)))
+    }
+}
 
 trait Type {
+    fn is_numeric(&self) -> bool;
     fn get_f64(&self) -> f64;
     fn get_string(&self) -> String;
     fn get_str(&self) -> &str;
@@ -173,9 +761,22 @@ trait Type {
 }
 
 impl Type for Value {
+    /// A number, or with the `bigint` feature also a wrapped big integer.
+    fn is_numeric(&self) -> bool {
+        #[cfg(feature = "bigint")]
+        {
+            if self.is_object() {
+                return big_integer(self).is_some();
+            }
+        }
+        self.is_number()
+    }
+
     fn get_f64(&self) -> f64 {
         match *self {
             Value::Number(ref n) => n.as_f64().unwrap(),
+            #[cfg(feature = "bigint")]
+            Value::Object(_) => big_integer(self).unwrap().to_string().parse().unwrap(),
             _ => panic!("not a number"),
         }
     }
@@ -1,6 +1,7 @@
 use crate::*;
 use {Function, Functions, Context, Contexts, Compiled, Value};
 use tree::Tree;
+use builtin::{Clock, Random};
 use Error;
 use serde::Serialize;
 use to_value;
@@ -46,9 +47,11 @@ impl Expr {
 
     pub fn exec(&self) -> Result<Value, Error> {
         if self.compiled.is_none() {
-            Tree::new(self.expression.clone()).compile()?(&self.contexts, &self.functions)
+            Tree::new(self.expression.clone()).compile()?(&self.contexts,
+                                                          &self.functions,
+                                                          &Options::default())
         } else {
-            self.compiled.as_ref().unwrap()(&self.contexts, &self.functions)
+            self.compiled.as_ref().unwrap()(&self.contexts, &self.functions, &Options::default())
         }
     }
 
@@ -78,10 +81,52 @@ impl fmt::Debug for Expr {
     }
 }
 
+/// How `/` behaves when both operands are integers.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Division {
+    /// `7 / 2` is `3.5`.
+    #[default]
+    Float,
+    /// `7 / 2` is `3`, truncated toward zero.
+    Truncate,
+    /// `6 / 2` is `3`, `7 / 2` is `Error::InexactDivision`.
+    Exact,
+}
+
+/// How integer `+`, `-`, `*`, `//`, `**`, unary `-`, `jumlah` and `kali_semua`
+/// behave when the result does not fit. Has no effect with the `bigint`
+/// feature, where integers never overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Overflow {
+    /// `9223372036854775807 + 1` is `Error::Overflow`.
+    #[default]
+    Checked,
+    /// `9223372036854775807 + 1` is `9223372036854775807`.
+    Saturating,
+    /// `9223372036854775807 + 1` is `9223372036854775808.0`.
+    Float,
+}
+
+/// Evaluation knobs passed down to every node while executing.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub division: Division,
+    pub overflow: Overflow,
+    /// `&&` and `||` coerce operands like `ke_bool` instead of requiring booleans.
+    pub truthiness: bool,
+    /// Identifiers found in no context are `Error::VariableNotFound` instead of null.
+    pub strict_variables: bool,
+    /// Seeded `acak` builtins, used before the unseeded ones.
+    pub(crate) random: Option<Random>,
+    /// Fixed `sekarang` and `hari_ini` builtins, used before the system clock.
+    pub(crate) clock: Option<Clock>,
+}
+
 pub struct ExecOptions<'a> {
     expr: &'a Expr,
     contexts: Option<&'a [Context]>,
     functions: Option<&'a Functions>,
+    options: Options,
 }
 
 impl<'a> ExecOptions<'a> {
@@ -90,6 +135,7 @@ impl<'a> ExecOptions<'a> {
             expr: expr,
             contexts: None,
             functions: None,
+            options: Options::default(),
         }
     }
 
@@ -103,6 +149,38 @@ impl<'a> ExecOptions<'a> {
         self
     }
 
+    pub fn division(&mut self, division: Division) -> &'a mut ExecOptions {
+        self.options.division = division;
+        self
+    }
+
+    pub fn overflow(&mut self, overflow: Overflow) -> &'a mut ExecOptions {
+        self.options.overflow = overflow;
+        self
+    }
+
+    pub fn truthiness(&mut self, truthiness: bool) -> &'a mut ExecOptions {
+        self.options.truthiness = truthiness;
+        self
+    }
+
+    pub fn strict_variables(&mut self, strict_variables: bool) -> &'a mut ExecOptions {
+        self.options.strict_variables = strict_variables;
+        self
+    }
+
+    /// Makes `acak`, `acak_antara` and `acak_pilih` deterministic for this execution.
+    pub fn seed(&mut self, seed: u64) -> &'a mut ExecOptions {
+        self.options.random = Some(Random::new(seed));
+        self
+    }
+
+    /// Freezes `sekarang` and `hari_ini` at the given epoch milliseconds for this execution.
+    pub fn clock(&mut self, millis: i64) -> &'a mut ExecOptions {
+        self.options.clock = Some(Clock::fixed(millis));
+        self
+    }
+
     pub fn exec(&self) -> Result<Value, Error> {
         let empty_contexts = create_empty_contexts();
         let empty_functions = Functions::new();
@@ -121,9 +199,9 @@ impl<'a> ExecOptions<'a> {
 
         let compiled = self.expr.get_compiled();
         if compiled.is_none() {
-            Tree::new(self.expr.expression.clone()).compile()?(contexts, functions)
+            Tree::new(self.expr.expression.clone()).compile()?(contexts, functions, &self.options)
         } else {
-            compiled.unwrap()(contexts, functions)
+            compiled.unwrap()(contexts, functions, &self.options)
         }
     }
 }
@@ -1,4 +1,4 @@
-#![recursion_limit="100"]
+#![recursion_limit="256"]
 extern crate pest;
 
 #[macro_use]
@@ -6,6 +6,30 @@ extern crate pest_derive;
 extern crate serde;
 extern crate serde_json;
 #[macro_use(quick_error)] extern crate quick_error;
+#[cfg(feature = "regex")]
+extern crate regex;
+#[cfg(feature = "unicode-segmentation")]
+extern crate unicode_segmentation;
+#[cfg(feature = "base64")]
+extern crate base64;
+#[cfg(feature = "hex")]
+extern crate hex;
+#[cfg(feature = "hash")]
+extern crate md5;
+#[cfg(feature = "hash")]
+extern crate sha1;
+#[cfg(feature = "hash")]
+extern crate sha2;
+#[cfg(feature = "uuid")]
+extern crate uuid;
+#[cfg(feature = "decimal")]
+extern crate rust_decimal;
+#[cfg(feature = "bigint")]
+extern crate num_bigint;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "chrono-tz")]
+extern crate chrono_tz;
 
 pub mod core;
 pub mod function;
@@ -22,7 +46,7 @@ use serde::Serialize;
 pub use serde_json::Value;
 
 // from baik
-pub use expr::ExecOptions;
+pub use expr::{ExecOptions, Options, Division, Overflow};
 pub use function::Function;
 pub use expr::Expr;
 use operator::Operator;
@@ -39,7 +63,7 @@ pub fn eval(expr: &str) -> Result<Value, Error> {
     Expr::new(expr).compile()?.exec()
 }
 
-pub type Compiled = Box<Fn(&[Context], &Functions) -> Result<Value, Error>>;
+pub type Compiled = Box<Fn(&[Context], &Functions, &Options) -> Result<Value, Error>>;
 
 quick_error! {
     /// Expression parsing error
@@ -85,6 +109,10 @@ quick_error! {
         ExpectedBoolean(value: Value) {
             display("Expected a boolean, found: {}", value)
         }
+        /// An identifier is in no context while `strict_variables` is set.
+        VariableNotFound(name: String) {
+            display("Variable not found: {}", name)
+        }
         /// Expected ident.
         ExpectedIdentifier {
             display("Expected ident.")
@@ -97,10 +125,18 @@ quick_error! {
         ExpectedObject {
             display("Expected object.")
         }
+        /// Expected string.
+        ExpectedString {
+            display("Expected string.")
+        }
         /// Expect number.
         ExpectedNumber {
             display("Expected number.")
         }
+        /// Expect a lambda like `x => x * 2` as the last argument.
+        ExpectedFunction {
+            display("Expected function.")
+        }
         /// Failed to parse, no final expression.
         NoFinalNode {
             display("Failed to parse, no final expression.")
@@ -117,10 +153,70 @@ quick_error! {
         UnsupportedTypes(a: String, b: String) {
             display("This two value types are different or do not support mathematical calculations: {}, {}", a, b)
         }
+        /// The value can not be converted by a cast operator like `int x`.
+        CanNotCast(value: Value, target: String) {
+            display("Can not cast {} to {}", value, target)
+        }
         /// Invalid range expression like `1..2..3`
         InvalidRange(ident: String) {
             display("Invalid range expression: {}", ident)
         }
+        /// Path string like `a.b[2].c` given to `jalur` is malformed.
+        InvalidPath(path: String) {
+            display("Invalid path: {}", path)
+        }
+        /// A date builtin got a value that is not a date or timestamp.
+        InvalidDate(value: String) {
+            display("Invalid date: {}", value)
+        }
+        /// A repeated, padded or generated string or array would exceed `MAX_LENGTH`.
+        TooLarge {
+            display("The result is too large.")
+        }
+        /// Division or remainder by zero.
+        DivisionByZero {
+            display("Division by zero.")
+        }
+        /// Integer arithmetic went out of range while `Overflow::Checked` is set.
+        Overflow {
+            display("Integer overflow.")
+        }
+        /// The result of a math builtin is NaN or infinite.
+        NotFinite {
+            display("The result is not a finite number.")
+        }
+        /// Integer division with a remainder while `Division::Exact` is set.
+        InexactDivision(a: Value, b: Value) {
+            display("Division is not exact: {} / {}", a, b)
+        }
+        /// The pattern given to a regex builtin does not compile.
+        InvalidRegex(detail: String) {
+            display("Invalid regex: {}", detail)
+        }
+        /// The string given to `dari_json` is not valid JSON.
+        InvalidJson(detail: String) {
+            display("Invalid JSON: {}", detail)
+        }
+        /// The string given to a decoding builtin is not validly encoded.
+        InvalidEncoding(detail: String) {
+            display("Invalid encoding: {}", detail)
+        }
+        /// Unpaired ternary, every `?` needs a `:` in the same brackets.
+        UnpairedTernary {
+            display("Unpaired ternary, every `?` needs a `:` in the same brackets.")
+        }
+        /// The right side of a pipeline `|>` is not a function.
+        InvalidPipeline {
+            display("The right side of a pipeline `|>` must be a function.")
+        }
+        /// A `=>` without parameters like `x` or `(acc, x)` in front of it.
+        InvalidLambda {
+            display("Invalid lambda, expected `x => body` or `(a, b) => body`.")
+        }
+        /// An object literal entry is not `key: value`.
+        InvalidObjectLiteral(entry: String) {
+            display("Invalid object literal entry: {}", entry)
+        }
         /// Can not add child node.
         CanNotAddChild {
             display("Can not add child node.")
@@ -1,7 +1,5 @@
 use crate::*;
 use operator::Operator;
-use Error;
-use Function;
 
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,24 +18,6 @@ impl Node {
         }
     }
 
-    pub fn check_function_args(&self, function: &Function) -> Result<(), Error> {
-        let args_length = self.children.len();
-
-        if let Some(len) = function.max_args {
-            if args_length > len {
-                return Err(Error::ArgumentsGreater(len));
-            }
-        }
-
-        if let Some(len) = function.min_args {
-            if args_length < len {
-                return Err(Error::ArgumentsLess(len));
-            }
-        }
-
-        Ok(())
-    }
-
     pub fn is_enough(&self) -> bool {
         let num = self.operator.get_max_args();
         if num.is_none() {
@@ -57,6 +37,13 @@ impl Node {
         }
     }
 
+    pub fn is_waiting_operand(&self) -> bool {
+        match self.operator.get_max_args() {
+            Some(max) => !self.closed && self.children.len() < max,
+            None => false,
+        }
+    }
+
     pub fn is_unclosed_arithmetic(&self) -> bool {
         return !self.closed && self.operator.can_have_child() && self.operator.can_have_child()
     }
@@ -84,7 +71,7 @@ impl Node {
 
     pub fn is_dot(&self) -> bool {
         match self.operator {
-            Operator::Dot(_) => true,
+            Operator::Dot(_) | Operator::OptionalDot(_) => true,
             _ => false,
         }
     }
@@ -12,7 +12,19 @@ pub enum Operator {
     Mul(u8),
     Sub(u8),
     Div(u8),
+    IntDiv(u8),
     Rem(u8),
+    Pow(u8),
+    Neg(u8),
+    Spread(u8),
+    CastInt(u8),
+    CastFloat(u8),
+    CastStr(u8),
+    BitAnd(u8),
+    BitOr(u8),
+    BitXor(u8),
+    Shl(u8),
+    Shr(u8),
     Not(u8),
     Eq(u8),
     Ne(u8),
@@ -20,9 +32,15 @@ pub enum Operator {
     Lt(u8),
     Ge(u8),
     Le(u8),
+    In(u8),
+    Pipe(u8),
     And(u8),
     Or(u8),
+    Question(u8),
+    Colon,
+    Slice(u8),
     Dot(u8),
+    OptionalDot(u8),
     LeftParenthesis,
     RightParenthesis,
     LeftSquareBracket(u8),
@@ -34,6 +52,10 @@ pub enum Operator {
     Function(String),
     Identifier(String),
     Value(Value),
+    Template(Vec<Node>),
+    Object(Vec<(String, Node)>),
+    Array(Vec<Node>),
+    Lambda(Vec<String>, Box<Node>),
 }
 
 impl Operator {
@@ -47,6 +69,11 @@ impl Operator {
     pub fn can_at_beginning(&self) -> bool {
         match *self {
             Operator::Not(_) |
+            Operator::Neg(_) |
+            Operator::Spread(_) |
+            Operator::CastInt(_) |
+            Operator::CastFloat(_) |
+            Operator::CastStr(_) |
             Operator::Function(_) |
             Operator::LeftParenthesis => true,
             _ => false,
@@ -56,10 +83,16 @@ impl Operator {
     pub fn get_max_args(&self) -> Option<usize> {
         match *self {
             Operator::Add(_) | Operator::Sub(_) | Operator::Mul(_) | Operator::Div(_) |
+            Operator::IntDiv(_) |
             Operator::Eq(_) | Operator::Ne(_) | Operator::Gt(_) | Operator::Lt(_) |
             Operator::Ge(_) | Operator::Le(_) | Operator::And(_) | Operator::Or(_) |
-            Operator::Rem(_) => Some(2),
-            Operator::Not(_) => Some(1),
+            Operator::Rem(_) | Operator::Pow(_) | Operator::In(_) | Operator::Pipe(_) |
+            Operator::BitAnd(_) |
+            Operator::BitOr(_) | Operator::BitXor(_) | Operator::Shl(_) | Operator::Shr(_) => Some(2),
+            Operator::Not(_) | Operator::Neg(_) | Operator::Spread(_) |
+            Operator::CastInt(_) | Operator::CastFloat(_) | Operator::CastStr(_) => Some(1),
+            Operator::Question(_) => Some(3),
+            Operator::Slice(_) => Some(2),
             Operator::Function(_) => None,
             _ => Some(0),
         }
@@ -68,10 +101,16 @@ impl Operator {
     pub fn get_min_args(&self) -> Option<usize> {
         match *self {
             Operator::Add(_) | Operator::Sub(_) | Operator::Mul(_) | Operator::Div(_) |
+            Operator::IntDiv(_) |
             Operator::Eq(_) | Operator::Ne(_) | Operator::Gt(_) | Operator::Lt(_) |
             Operator::Ge(_) | Operator::Le(_) | Operator::And(_) | Operator::Or(_) |
-            Operator::Rem(_) => Some(2),
-            Operator::Not(_) => Some(1),
+            Operator::Rem(_) | Operator::Pow(_) | Operator::In(_) | Operator::Pipe(_) |
+            Operator::BitAnd(_) |
+            Operator::BitOr(_) | Operator::BitXor(_) | Operator::Shl(_) | Operator::Shr(_) => Some(2),
+            Operator::Not(_) | Operator::Neg(_) | Operator::Spread(_) |
+            Operator::CastInt(_) | Operator::CastFloat(_) | Operator::CastStr(_) => Some(1),
+            Operator::Question(_) => Some(3),
+            Operator::Slice(_) => Some(2),
             Operator::Function(_) => None,
             _ => Some(0),
         }
@@ -82,6 +121,7 @@ impl Operator {
             Operator::Add(priority) |
             Operator::Sub(priority) |
             Operator::Div(priority) |
+            Operator::IntDiv(priority) |
             Operator::Mul(priority) |
             Operator::Eq(priority) |
             Operator::Ne(priority) |
@@ -89,15 +129,105 @@ impl Operator {
             Operator::Lt(priority) |
             Operator::Ge(priority) |
             Operator::Le(priority) |
+            Operator::In(priority) |
+            Operator::Pipe(priority) |
             Operator::And(priority) |
             Operator::Or(priority) |
-            Operator::Rem(priority) => priority,
+            Operator::Rem(priority) |
+            Operator::Pow(priority) |
+            Operator::Neg(priority) |
+            Operator::Spread(priority) |
+            Operator::CastInt(priority) |
+            Operator::CastFloat(priority) |
+            Operator::CastStr(priority) |
+            Operator::BitAnd(priority) |
+            Operator::BitOr(priority) |
+            Operator::BitXor(priority) |
+            Operator::Shl(priority) |
+            Operator::Shr(priority) |
+            Operator::Question(priority) |
+            Operator::Slice(priority) => priority,
             Operator::Value(_) |
-            Operator::Identifier(_) => 0,
+            Operator::Identifier(_) |
+            Operator::Template(_) |
+            Operator::Object(_) |
+            Operator::Array(_) |
+            Operator::Lambda(..) => 0,
             _ => 99,
         }
     }
 
+    pub fn is_unary(&self) -> bool {
+        match *self {
+            Operator::Not(_) |
+            Operator::Neg(_) |
+            Operator::Spread(_) |
+            Operator::CastInt(_) |
+            Operator::CastFloat(_) |
+            Operator::CastStr(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn ends_value(&self) -> bool {
+        match *self {
+            Operator::Value(_) |
+            Operator::Identifier(_) |
+            Operator::Template(_) |
+            Operator::Object(_) |
+            Operator::Array(_) |
+            Operator::Lambda(..) |
+            Operator::RightParenthesis |
+            Operator::RightSquareBracket => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_right_associative(&self) -> bool {
+        match *self {
+            Operator::Pow(_) |
+            Operator::Question(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_cast(&self) -> bool {
+        match *self {
+            Operator::CastInt(_) |
+            Operator::CastFloat(_) |
+            Operator::CastStr(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_question(&self) -> bool {
+        match *self {
+            Operator::Question(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_spread(&self) -> bool {
+        match *self {
+            Operator::Spread(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_lambda(&self) -> bool {
+        match *self {
+            Operator::Lambda(..) => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_slice(&self) -> bool {
+        match *self {
+            Operator::Slice(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn is_left_parenthesis(&self) -> bool {
         *self == Operator::LeftParenthesis
     }
@@ -123,10 +253,21 @@ impl Operator {
         }
     }
 
+    pub fn is_optional_dot(&self) -> bool {
+        match *self {
+            Operator::OptionalDot(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn is_value_or_ident(&self) -> bool {
         match *self {
             Operator::Value(_) |
-            Operator::Identifier(_) => true,
+            Operator::Identifier(_) |
+            Operator::Template(_) |
+            Operator::Object(_) |
+            Operator::Array(_) |
+            Operator::Lambda(..) => true,
             _ => false,
         }
     }
@@ -137,17 +278,34 @@ impl Operator {
             Operator::Add(_) |
             Operator::Sub(_) |
             Operator::Div(_) |
+            Operator::IntDiv(_) |
             Operator::Mul(_) |
             Operator::Rem(_) |
+            Operator::Pow(_) |
+            Operator::BitAnd(_) |
+            Operator::BitOr(_) |
+            Operator::BitXor(_) |
+            Operator::Shl(_) |
+            Operator::Shr(_) |
             Operator::Eq(_) |
             Operator::Ne(_) |
             Operator::Gt(_) |
             Operator::Lt(_) |
             Operator::And(_) |
             Operator::Or(_) |
+            Operator::Question(_) |
+            Operator::Slice(_) |
             Operator::Ge(_) |
+            Operator::In(_) |
+            Operator::Pipe(_) |
             Operator::Not(_) |
+            Operator::Neg(_) |
+            Operator::Spread(_) |
+            Operator::CastInt(_) |
+            Operator::CastFloat(_) |
+            Operator::CastStr(_) |
             Operator::Dot(_) |
+            Operator::OptionalDot(_) |
             Operator::LeftSquareBracket(_) |
             Operator::Le(_) => true,
             _ => false,
@@ -193,31 +351,48 @@ impl FromStr for Operator {
 
     fn from_str(raw: &str) -> Result<Operator, Error> {
         match raw {
-            "+" => Ok(Operator::Add(8)),
-            "-" => Ok(Operator::Sub(8)),
-            "*" => Ok(Operator::Mul(10)),
-            "/" => Ok(Operator::Div(10)),
-            "%" => Ok(Operator::Rem(10)),
+            "+" => Ok(Operator::Add(12)),
+            "-" => Ok(Operator::Sub(12)),
+            "*" => Ok(Operator::Mul(14)),
+            "/" => Ok(Operator::Div(14)),
+            "//" => Ok(Operator::IntDiv(14)),
+            "%" => Ok(Operator::Rem(14)),
+            "**" => Ok(Operator::Pow(16)),
+            "&" => Ok(Operator::BitAnd(10)),
+            "|" => Ok(Operator::BitOr(8)),
+            "^" => Ok(Operator::BitXor(9)),
+            "<<" => Ok(Operator::Shl(11)),
+            ">>" => Ok(Operator::Shr(11)),
             "(" => Ok(Operator::LeftParenthesis),
             ")" => Ok(Operator::RightParenthesis),
             "[" => Ok(Operator::LeftSquareBracket(100)),
             "]" => Ok(Operator::RightSquareBracket),
             "." => Ok(Operator::Dot(100)),
+            "?." => Ok(Operator::OptionalDot(100)),
+            "..." => Ok(Operator::Spread(15)),
             "\"" => Ok(Operator::DoubleQuotes),
             "'" => Ok(Operator::SingleQuote),
             " " => Ok(Operator::WhiteSpace),
             "," => Ok(Operator::Comma),
-            "!" => Ok(Operator::Not(99)),
-            "false" => Ok(Operator::Value(to_value(false))),
-            "true" => Ok(Operator::Value(to_value(true))),
+            "?" => Ok(Operator::Question(1)),
+            ":" => Ok(Operator::Colon),
+            "!" | "bukan" => Ok(Operator::Not(99)),
+            "int" | "bulat" => Ok(Operator::CastInt(15)),
+            "float" | "pecahan" => Ok(Operator::CastFloat(15)),
+            "str" | "teks" => Ok(Operator::CastStr(15)),
+            "false" | "salah" => Ok(Operator::Value(to_value(false))),
+            "true" | "benar" => Ok(Operator::Value(to_value(true))),
+            "null" | "nihil" => Ok(Operator::Value(Value::Null)),
             "==" => Ok(Operator::Eq(6)),
             "!=" => Ok(Operator::Ne(6)),
             ">" => Ok(Operator::Gt(6)),
             "<" => Ok(Operator::Lt(6)),
             ">=" => Ok(Operator::Ge(6)),
             "<=" => Ok(Operator::Le(6)),
-            "&&" => Ok(Operator::And(4)),
-            "||" => Ok(Operator::Or(2)),
+            "in" => Ok(Operator::In(6)),
+            "|>" => Ok(Operator::Pipe(7)),
+            "&&" | "dan" => Ok(Operator::And(4)),
+            "||" | "atau" => Ok(Operator::Or(2)),
             _ => Ok(Operator::Identifier(raw.to_owned())),
         }
     }
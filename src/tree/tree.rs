@@ -3,10 +3,12 @@ use std::str::FromStr;
 use std::clone::Clone;
 use serde_json::Value;
 use to_value;
-use self::core::math::Math;
+use self::core::math::{Math, add, subtract, multiply, divide, int_divide, power, negate, truthy};
+#[cfg(feature = "bigint")]
+use self::core::math::{big_value, export_big_integers};
 use operator::Operator;
 use node::Node;
-use {Context, Functions};
+use {Context, Functions, Options};
 use Error;
 use Compiled;
 use builtin::BuiltIn;
@@ -29,22 +31,43 @@ impl Tree {
         let mut found_quote = false;
         let mut pos = Vec::new();
 
-        for (index, cur) in self.raw.chars().enumerate() {
-            match cur {
+        let chars = self.raw.chars().collect::<Vec<_>>();
+        let offsets = self.raw
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain(Some(self.raw.len()))
+            .collect::<Vec<_>>();
+        let mut index = 0;
+        while index < chars.len() {
+            let width = if found_quote { 1 } else { compound_width(&chars[index..]) };
+            if width > 1 {
+                pos.push(offsets[index]);
+                pos.push(offsets[index + width]);
+                index += width;
+                continue;
+            }
+
+            if !found_quote && is_exponent_sign(&chars, index) {
+                index += 1;
+                continue;
+            }
+
+            match chars[index] {
                 '(' | ')' | '+' | '-' | '*' | '/' | ',' | ' ' | '!' | '=' | '>' | '<' | '\'' |
-                '[' | ']' | '.' | '%' | '&' | '|' => {
+                '[' | ']' | '{' | '}' | '.' | '%' | '&' | '|' | '^' | '?' | ':' => {
                     if !found_quote {
-                        pos.push(index);
-                        pos.push(index + 1);
+                        pos.push(offsets[index]);
+                        pos.push(offsets[index + 1]);
                     }
                 }
                 '"' => {
                     found_quote = !found_quote;
-                    pos.push(index);
-                    pos.push(index + 1);
+                    pos.push(offsets[index]);
+                    pos.push(offsets[index + 1]);
                 }
                 _ => (),
             }
+            index += 1;
         }
 
         pos.push(self.raw.len());
@@ -58,9 +81,12 @@ impl Tree {
         let mut start;
         let mut end = 0;
         let mut parenthesis = 0;
+        let mut brackets = Vec::new();
+        let mut ternaries = Vec::new();
         let mut quote = None;
         let mut prev = String::new();
         let mut number = String::new();
+        let mut nested_end = 0;
 
         for pos_ref in &self.pos {
             let pos = *pos_ref;
@@ -71,6 +97,11 @@ impl Tree {
                 end = pos;
             }
 
+            // lambda bodies and literal entries were already parsed as their own trees
+            if end <= nested_end {
+                continue;
+            }
+
             let raw = self.raw[start..end].to_owned();
 
             if raw.is_empty() {
@@ -82,7 +113,11 @@ impl Tree {
                 Operator::DoubleQuotes | Operator::SingleQuote => {
                     if quote.is_some() {
                         if quote.as_ref() == Some(&operator) {
-                            operators.push(Operator::Value(to_value(&prev)));
+                            if operator == Operator::DoubleQuotes && prev.contains("${") {
+                                operators.push(parse_template(&prev)?);
+                            } else {
+                                operators.push(Operator::Value(to_value(&prev)));
+                            }
                             prev.clear();
                             quote = None;
                             continue;
@@ -101,7 +136,8 @@ impl Tree {
                 continue;
             }
 
-            if parse_number(&raw).is_some() || operator.is_dot() {
+            if parse_number(&raw).is_some() || operator.is_dot() ||
+               raw == "=" && number.ends_with("..") {
                 number += &raw;
                 continue;
             } else if !number.is_empty() {
@@ -109,6 +145,15 @@ impl Tree {
                 number.clear();
             }
 
+            if raw == "=>" {
+                let params = lambda_params(&mut operators)?;
+                nested_end = end + body_end(&self.raw[end..]);
+                let body = parse_expression(&self.raw[end..nested_end])?;
+                operators.push(Operator::Lambda(params, Box::new(body)));
+                prev = raw;
+                continue;
+            }
+
             if raw == "=" {
                 if prev == "!" || prev == ">" || prev == "<" || prev == "=" {
                     prev.push_str("=");
@@ -131,23 +176,32 @@ impl Tree {
                 prev.clear();
             }
 
-            if (raw == "&" || raw == "|") && (prev == "&" || prev == "|") {
-                if raw == prev {
-                    prev.push_str(&raw);
-                    operators.push(Operator::from_str(&prev).unwrap());
-                    prev.clear();
-                    continue;
-                } else {
-                    return Err(Error::UnsupportedOperator(prev));
-                }
-            } else if raw == "&" || raw == "|" {
+            // keywords are field names after a dot, e.g. `user.in` or `user.teks`, and
+            // cast keywords without an operand are names too, e.g. `teks[0]` or a bare `int`
+            let is_word = raw.chars().all(|c| c.is_alphanumeric() || c == '_');
+            let operator = if is_word && after_member_access(&operators) ||
+                              operator.is_cast() && !operand_follows(&self.raw[end..]) {
+                Operator::Identifier(raw.clone())
+            } else {
+                operator
+            };
+
+            // `{` always starts a literal, `[` only where no value precedes it to index
+            if raw == "{" || raw == "[" && !operators.last().map(Operator::ends_value).unwrap_or(false) {
+                let close = closing_bracket(&self.raw[end..]).ok_or(Error::UnpairedBrackets)?;
+                let entries = &self.raw[end..end + close];
+                operators.push(if raw == "{" { parse_object(entries)? } else { parse_array(entries)? });
+                nested_end = end + close + 1;
                 prev = raw;
                 continue;
+            } else if raw == "}" {
+                return Err(Error::UnpairedBrackets);
             }
 
             match operator {
                 Operator::LeftParenthesis => {
                     parenthesis += 1;
+                    brackets.push(Operator::LeftParenthesis);
 
                     if !operators.is_empty() {
                         let prev_operator = operators.pop().unwrap();
@@ -161,7 +215,29 @@ impl Tree {
                         }
                     }
                 }
-                Operator::RightParenthesis => parenthesis -= 1,
+                Operator::RightParenthesis => {
+                    parenthesis -= 1;
+                    close_scope(&mut brackets, &ternaries)?;
+                }
+                Operator::LeftSquareBracket(_) => brackets.push(operator.clone()),
+                Operator::RightSquareBracket => close_scope(&mut brackets, &ternaries)?,
+                Operator::Question(_) => ternaries.push(brackets.len()),
+                Operator::Colon => {
+                    if ternaries.last() == Some(&brackets.len()) {
+                        ternaries.pop();
+                    } else if brackets.last().map(Operator::is_left_square_bracket).unwrap_or(false) {
+                        prev = raw;
+                        operators.push(Operator::Slice(1));
+                        continue;
+                    } else {
+                        return Err(Error::UnpairedTernary);
+                    }
+                }
+                Operator::Sub(_) if !operators.last().map(Operator::ends_value).unwrap_or(false) => {
+                    prev = raw;
+                    operators.push(Operator::Neg(15));
+                    continue;
+                }
                 Operator::WhiteSpace => continue,
                 _ => (),
             }
@@ -176,6 +252,8 @@ impl Tree {
 
         if parenthesis != 0 {
             Err(Error::UnpairedBrackets)
+        } else if !ternaries.is_empty() {
+            Err(Error::UnpairedTernary)
         } else {
             self.operators = operators;
             Ok(())
@@ -191,7 +269,13 @@ impl Tree {
                 Operator::Sub(priority) |
                 Operator::Mul(priority) |
                 Operator::Div(priority) |
+                Operator::IntDiv(priority) |
                 Operator::Not(priority) |
+                Operator::Neg(priority) |
+                Operator::Spread(priority) |
+                Operator::CastInt(priority) |
+                Operator::CastFloat(priority) |
+                Operator::CastStr(priority) |
                 Operator::Eq(priority) |
                 Operator::Ne(priority) |
                 Operator::Gt(priority) |
@@ -200,18 +284,35 @@ impl Tree {
                 Operator::And(priority) |
                 Operator::Or(priority) |
                 Operator::Le(priority) |
+                Operator::In(priority) |
+                Operator::Pipe(priority) |
                 Operator::Dot(priority) |
+                Operator::OptionalDot(priority) |
                 Operator::LeftSquareBracket(priority) |
-                Operator::Rem(priority) => {
+                Operator::Rem(priority) |
+                Operator::Pow(priority) |
+                Operator::BitAnd(priority) |
+                Operator::BitOr(priority) |
+                Operator::BitXor(priority) |
+                Operator::Shl(priority) |
+                Operator::Shr(priority) |
+                Operator::Question(priority) => {
+                    if !operator.is_unary() {
+                        attach_to_waiting(&mut parsing_nodes, operator, priority);
+                    }
+
                     if !parsing_nodes.is_empty() {
                         let prev = parsing_nodes.pop().unwrap();
                         if prev.is_value_or_full_children() {
-                            if prev.operator.get_priority() < priority && !prev.closed {
+                            let prev_priority = prev.operator.get_priority();
+                            if (prev_priority < priority ||
+                                prev_priority == priority && operator.is_right_associative()) &&
+                               !prev.closed {
                                 parsing_nodes.extend_from_slice(&rob_to(prev, operator.to_node()));
                             } else {
                                 parsing_nodes.push(operator.children_to_node(vec![prev]));
                             }
-                        } else if prev.operator.can_at_beginning() {
+                        } else if prev.operator.can_at_beginning() || operator.is_unary() {
                             parsing_nodes.push(prev);
                             parsing_nodes.push(operator.to_node());
                         } else {
@@ -222,7 +323,14 @@ impl Tree {
                     } else {
                         return Err(Error::StartWithNonValueOperator);
                     }
+
+                    // the true branch is parsed like a bracket closed by `:`
+                    if operator.is_question() {
+                        parsing_nodes.push(Operator::LeftParenthesis.to_node());
+                    }
                 }
+                Operator::Colon => close_bracket(&mut parsing_nodes, Operator::LeftParenthesis)?,
+                Operator::Slice(_) => start_slice(&mut parsing_nodes)?,
                 Operator::Function(_) |
                 Operator::LeftParenthesis => parsing_nodes.push(operator.to_node()),
                 Operator::Comma => close_comma(&mut parsing_nodes)?,
@@ -231,12 +339,18 @@ impl Tree {
                     close_bracket(&mut parsing_nodes, operator.get_left())?
                 }
                 Operator::Value(_) |
-                Operator::Identifier(_) => append_value_to_last_node(&mut parsing_nodes, operator)?,
+                Operator::Identifier(_) |
+                Operator::Template(_) |
+                Operator::Object(_) |
+                Operator::Array(_) |
+                Operator::Lambda(..) => append_value_to_last_node(&mut parsing_nodes, operator)?,
                 _ => (),
             }
         }
 
-        self.node = Some(get_final_node(parsing_nodes)?);
+        let node = get_final_node(parsing_nodes)?;
+        check_operands(&node)?;
+        self.node = Some(rewrite_pipeline(node)?);
         Ok(())
     }
 
@@ -247,114 +361,298 @@ impl Tree {
         let node = self.node.unwrap();
         let builtin = BuiltIn::new();
 
-        Ok(Box::new(move |contexts, functions| -> Result<Value, Error> {
-            return exec_node(&node, &builtin, contexts, functions);
+        Ok(Box::new(move |contexts, functions, options| -> Result<Value, Error> {
+            let result = exec_node(&node, &builtin, &Scope::Contexts(contexts), functions, options);
+            #[cfg(feature = "bigint")]
+            let result = result.map(export_big_integers);
+            return result;
 
             fn exec_node(node: &Node,
                          builtin: &Functions,
-                         contexts: &[Context],
-                         functions: &Functions)
+                         contexts: &Scope,
+                         functions: &Functions,
+                         options: &Options)
                          -> Result<Value, Error> {
                 match node.operator {
                     Operator::Add(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
-                            ?
-                            .add(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                        add(&exec_node(&node.get_first_child(), builtin, contexts, functions, options)?,
+                            &exec_node(&node.get_last_child(), builtin, contexts, functions, options)?,
+                            options.overflow)
                     }
                     Operator::Mul(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
-                            ?
-                            .mul(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                        multiply(&exec_node(&node.get_first_child(), builtin, contexts, functions, options)?,
+                            &exec_node(&node.get_last_child(), builtin, contexts, functions, options)?,
+                            options.overflow)
                     }
                     Operator::Sub(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
-                            ?
-                            .sub(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                        subtract(&exec_node(&node.get_first_child(), builtin, contexts, functions, options)?,
+                            &exec_node(&node.get_last_child(), builtin, contexts, functions, options)?,
+                            options.overflow)
                     }
                     Operator::Div(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
-                            ?
-                            .div(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                        divide(&exec_node(&node.get_first_child(), builtin, contexts, functions, options)?,
+                               &exec_node(&node.get_last_child(), builtin, contexts, functions, options)?,
+                               options.division,
+                               options.overflow)
+                    }
+                    Operator::IntDiv(_) => {
+                        int_divide(&exec_node(&node.get_first_child(), builtin, contexts, functions, options)?,
+                                   &exec_node(&node.get_last_child(), builtin, contexts, functions, options)?,
+                                   options.overflow)
                     }
                     Operator::Rem(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
+                            ?
+                            .rem(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
+                    }
+                    Operator::Pow(_) => {
+                        power(&exec_node(&node.get_first_child(), builtin, contexts, functions, options)?,
+                              &exec_node(&node.get_last_child(), builtin, contexts, functions, options)?,
+                              options.overflow)
+                    }
+                    Operator::Neg(_) => {
+                        negate(&exec_node(&node.get_first_child(), builtin, contexts, functions, options)?,
+                               options.overflow)
+                    }
+                    Operator::CastInt(_) => {
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)?
+                            .cast_int()
+                    }
+                    Operator::CastFloat(_) => {
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)?
+                            .cast_float()
+                    }
+                    Operator::CastStr(_) => {
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)?
+                            .cast_str()
+                    }
+                    Operator::BitAnd(_) => {
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
+                            ?
+                            .bit_and(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
+                    }
+                    Operator::BitOr(_) => {
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
+                            ?
+                            .bit_or(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
+                    }
+                    Operator::BitXor(_) => {
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
+                            ?
+                            .bit_xor(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
+                    }
+                    Operator::Shl(_) => {
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
                             ?
-                            .rem(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                            .shl(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
+                    }
+                    Operator::Shr(_) => {
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
+                            ?
+                            .shr(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
                     }
                     Operator::Eq(_) => {
-                        Math::eq(&exec_node(&node.get_first_child(), builtin, contexts, functions)?,
-                                 &exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                        Math::eq(&exec_node(&node.get_first_child(), builtin, contexts, functions, options)?,
+                                 &exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
                     }
                     Operator::Ne(_) => {
-                        Math::ne(&exec_node(&node.get_first_child(), builtin, contexts, functions)?,
-                                 &exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                        Math::ne(&exec_node(&node.get_first_child(), builtin, contexts, functions, options)?,
+                                 &exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
                     }
                     Operator::Gt(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
                             ?
-                            .gt(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                            .gt(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
                     }
                     Operator::Lt(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
                             ?
-                            .lt(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                            .lt(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
                     }
                     Operator::Ge(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
                             ?
-                            .ge(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                            .ge(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
                     }
                     Operator::Le(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
+                            ?
+                            .le(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
+                    }
+                    Operator::In(_) => {
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
                             ?
-                            .le(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                            .is_in(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
+                    }
+                    Operator::And(_) if options.truthiness => {
+                        let a = exec_node(&node.get_first_child(), builtin, contexts, functions, options)?;
+                        let b = exec_node(&node.get_last_child(), builtin, contexts, functions, options)?;
+                        Ok(to_value(truthy(&a) && truthy(&b)))
+                    }
+                    Operator::Or(_) if options.truthiness => {
+                        let a = exec_node(&node.get_first_child(), builtin, contexts, functions, options)?;
+                        let b = exec_node(&node.get_last_child(), builtin, contexts, functions, options)?;
+                        Ok(to_value(truthy(&a) || truthy(&b)))
                     }
                     Operator::And(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
                             ?
-                            .and(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                            .and(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
                     }
                     Operator::Or(_) => {
-                        exec_node(&node.get_first_child(), builtin, contexts, functions)
+                        exec_node(&node.get_first_child(), builtin, contexts, functions, options)
                             ?
-                            .or(&exec_node(&node.get_last_child(), builtin, contexts, functions)?)
+                            .or(&exec_node(&node.get_last_child(), builtin, contexts, functions, options)?)
+                    }
+                    Operator::Function(ref ident) if !functions.contains_key(ident) &&
+                                                     BuiltIn::lazy(ident).is_some() => {
+                        let function = BuiltIn::lazy(ident).unwrap();
+                        let len = node.children.len();
+                        if len != function.args {
+                            return Err(if len > function.args {
+                                Error::ArgumentsGreater(function.args)
+                            } else {
+                                Error::ArgumentsLess(function.args)
+                            });
+                        }
+
+                        let arg = |index: usize| {
+                            exec_node(&node.children[index], builtin, contexts, functions, options)
+                        };
+                        (function.compiled)(&arg)
+                    }
+                    Operator::Function(ref ident) if !functions.contains_key(ident) &&
+                                                     node.children.last().is_some_and(|child| child.operator.is_lambda()) => {
+                        let function = BuiltIn::higher_order(ident)
+                            .ok_or_else(|| Error::FunctionNotExists(ident.to_owned()))?;
+                        let (lambda, args) = node.children.split_last().unwrap();
+                        if args.len() != function.args {
+                            return Err(if args.len() > function.args {
+                                Error::ArgumentsGreater(function.args + 1)
+                            } else {
+                                Error::ArgumentsLess(function.args + 1)
+                            });
+                        }
+
+                        let mut values = Vec::new();
+                        for arg in args {
+                            values.push(exec_node(arg, builtin, contexts, functions, options)?);
+                        }
+                        let (params, body) = match lambda.operator {
+                            Operator::Lambda(ref params, ref body) => (params, body),
+                            _ => unreachable!(),
+                        };
+                        let call = |args: &[Value]| {
+                            let mut layer = Context::new();
+                            for (index, param) in params.iter().enumerate() {
+                                layer.insert(param.clone(), args.get(index).cloned().unwrap_or(Value::Null));
+                            }
+                            exec_node(body, builtin, &Scope::Layer(&layer, contexts), functions, options)
+                        };
+                        (function.compiled)(values, &call)
                     }
                     Operator::Function(ref ident) => {
                         let function_option = if functions.contains_key(ident) {
                             functions.get(ident)
                         } else {
-                            builtin.get(ident)
+                            options.random.as_ref()
+                                .and_then(|random| random.get(ident))
+                                .or_else(|| options.clock.as_ref().and_then(|clock| clock.get(ident)))
+                                .or_else(|| builtin.get(ident))
                         };
 
                         if function_option.is_some() {
                             let function = function_option.unwrap();
-                            node.check_function_args(function)?;
-                            let mut values = Vec::new();
-                            for node in &node.children {
-                                values.push(exec_node(node, builtin, contexts, functions)?);
-                            }
+                            let values = exec_args(&node.children, builtin, contexts, functions, options)?;
+                            check_function_args(function, values.len())?;
+                            #[cfg(feature = "bigint")]
+                            let values = if functions.contains_key(ident) {
+                                values.into_iter().map(export_big_integers).collect()
+                            } else {
+                                values
+                            };
                             (function.compiled)(values)
+                        } else if let Some(aggregate) = BuiltIn::aggregate(ident) {
+                            aggregate.exec(exec_args(&node.children, builtin, contexts, functions, options)?,
+                                           options.overflow)
+                        } else if let Some(function) = BuiltIn::higher_order(ident) {
+                            // a higher-order builtin whose last argument is not a lambda
+                            let len = node.children.len();
+                            Err(if len > function.args + 1 {
+                                Error::ArgumentsGreater(function.args + 1)
+                            } else if len < function.args + 1 {
+                                Error::ArgumentsLess(function.args + 1)
+                            } else {
+                                Error::ExpectedFunction
+                            })
                         } else {
                             Err(Error::FunctionNotExists(ident.to_owned()))
                         }
                     }
+                    Operator::Question(_) => {
+                        let condition =
+                            exec_node(&node.get_first_child(), builtin, contexts, functions, options)?;
+                        match condition {
+                            Value::Bool(true) => {
+                                exec_node(&node.children[1], builtin, contexts, functions, options)
+                            }
+                            Value::Bool(false) | Value::Null => {
+                                exec_node(&node.get_last_child(), builtin, contexts, functions, options)
+                            }
+                            _ => Err(Error::ExpectedBoolean(condition)),
+                        }
+                    }
                     Operator::Value(ref value) => Ok(value.clone()),
+                    Operator::Template(ref parts) => {
+                        let mut string = String::new();
+                        for part in parts {
+                            let part = exec_node(part, builtin, contexts, functions, options)?;
+                            if let Value::String(ref part) = part.cast_str()? {
+                                string += part;
+                            }
+                        }
+                        Ok(to_value(string))
+                    }
+                    Operator::Array(ref items) => {
+                        let mut array = Vec::new();
+                        for item in items {
+                            if item.operator.is_spread() {
+                                match exec_node(&item.get_first_child(), builtin, contexts, functions, options)? {
+                                    Value::Array(items) => array.extend(items),
+                                    _ => return Err(Error::ExpectedArray),
+                                }
+                            } else {
+                                array.push(exec_node(item, builtin, contexts, functions, options)?);
+                            }
+                        }
+                        Ok(Value::Array(array))
+                    }
+                    Operator::Object(ref entries) => {
+                        let mut object = serde_json::Map::new();
+                        for (key, value) in entries {
+                            object.insert(key.clone(), exec_node(value, builtin, contexts, functions, options)?);
+                        }
+                        Ok(Value::Object(object))
+                    }
                     Operator::Not(_) => {
                         let value =
-                            exec_node(&node.get_first_child(), builtin, contexts, functions)?;
+                            exec_node(&node.get_first_child(), builtin, contexts, functions, options)?;
                         match value {
                             Value::Bool(boolean) => Ok(Value::Bool(!boolean)),
                             Value::Null => Ok(Value::Bool(true)),
                             _ => Err(Error::ExpectedBoolean(value)),
                         }
                     }
-                    Operator::Dot(_) => {
+                    Operator::Dot(_) |
+                    Operator::OptionalDot(_) => {
                         let mut value = None;
                         for child in &node.children {
                             if value.is_none() {
-                                let name = exec_node(child, builtin, contexts, functions)?;
-                                if name.is_string() {
-                                    value = find(contexts, name.as_str().unwrap());
+                                let name = exec_node(child, builtin, contexts, functions, options)?;
+                                if node.operator.is_optional_dot() && !name.is_object() {
+                                    return Ok(Value::Null);
+                                } else if name.is_string() {
+                                    value = contexts.find(name.as_str().unwrap());
                                     if value.is_none() {
                                         return Ok(Value::Null);
                                     }
@@ -386,14 +684,25 @@ impl Tree {
                     Operator::LeftSquareBracket(_) => {
                         let mut value = None;
                         for child in &node.children {
-                            let name = exec_node(child, builtin, contexts, functions)?;
+                            if child.operator.is_slice() {
+                                let start =
+                                    exec_node(&child.get_first_child(), builtin, contexts, functions, options)?;
+                                let end = if child.children.len() > 1 {
+                                    exec_node(&child.get_last_child(), builtin, contexts, functions, options)?
+                                } else {
+                                    Value::Null
+                                };
+                                return slice(value.as_ref().ok_or(Error::ExpectedArray)?, &start, &end);
+                            }
+
+                            let name = exec_node(child, builtin, contexts, functions, options)?;
                             if value.is_none() {
-                                if name.is_string() {
-                                    value = find(contexts, name.as_str().unwrap());
+                                if name.is_string() && !node.get_last_child().operator.is_slice() {
+                                    value = contexts.find(name.as_str().unwrap());
                                     if value.is_none() {
                                         return Ok(Value::Null);
                                     }
-                                } else if name.is_array() {
+                                } else if name.is_string() || name.is_array() {
                                     value = Some(name);
                                 } else if name.is_object() {
                                     value = Some(name);
@@ -412,13 +721,11 @@ impl Tree {
                                     return Err(Error::ExpectedIdentifier);
                                 }
                             } else {
-                                if name.is_u64() {
+                                if name.is_u64() || name.is_i64() {
                                     if value.as_ref().unwrap().is_array() {
-                                        value = value.as_ref()
-                                            .unwrap()
-                                            .as_array()
-                                            .unwrap()
-                                            .get(name.as_u64().unwrap() as usize)
+                                        let array = value.as_ref().unwrap().as_array().unwrap();
+                                        value = array_index(&name, array.len())
+                                            .and_then(|index| array.get(index))
                                             .cloned();
                                     } else {
                                         return Err(Error::ExpectedArray);
@@ -436,13 +743,17 @@ impl Tree {
                     }
                     Operator::Identifier(ref ident) => {
                         let number = parse_number(ident);
-                        if number.is_some() {
+                        if number == Some(Value::Null) {
+                            // `1e400` overflows to infinity, which JSON numbers cannot hold
+                            Err(Error::NotFinite)
+                        } else if number.is_some() {
                             Ok(number.unwrap())
                         } else if is_range(ident) {
                             parse_range(ident)
                         } else {
-                            match find(contexts, ident) {
+                            match contexts.find(ident) {
                                 Some(value) => Ok(value),
+                                None if options.strict_variables => Err(Error::VariableNotFound(ident.clone())),
                                 None => Ok(Value::Null),
                             }
                         }
@@ -450,6 +761,27 @@ impl Tree {
                     _ => Err(Error::CanNotExec(node.operator.clone())),
                 }
             }
+
+            /// Function arguments in order, `...arr` spreads its items.
+            fn exec_args(children: &[Node],
+                         builtin: &Functions,
+                         contexts: &Scope,
+                         functions: &Functions,
+                         options: &Options)
+                         -> Result<Vec<Value>, Error> {
+                let mut values = Vec::new();
+                for node in children {
+                    if node.operator.is_spread() {
+                        match exec_node(&node.get_first_child(), builtin, contexts, functions, options)? {
+                            Value::Array(array) => values.extend(array),
+                            _ => return Err(Error::ExpectedArray),
+                        }
+                    } else {
+                        values.push(exec_node(node, builtin, contexts, functions, options)?);
+                    }
+                }
+                Ok(values)
+            }
         }))
     }
 }
@@ -465,7 +797,7 @@ fn append_value_to_last_node(parsing_nodes: &mut Vec<Node>,
             prev.add_child(node);
             prev.closed = true;
             parsing_nodes.push(prev);
-        } else if prev.is_left_square_bracket() {
+        } else if prev.is_unclosed_square_bracket() {
             parsing_nodes.push(prev);
             parsing_nodes.push(node);
         } else if prev.is_value_or_full_children() {
@@ -486,6 +818,188 @@ fn append_value_to_last_node(parsing_nodes: &mut Vec<Node>,
     Ok(())
 }
 
+/// Parses a nested expression like a `${...}` part or a lambda body.
+fn parse_expression(raw: &str) -> Result<Node, Error> {
+    let mut tree = Tree::new(raw);
+    tree.parse_pos()?;
+    tree.parse_operators()?;
+    tree.parse_node()?;
+    Ok(tree.node.unwrap())
+}
+
+/// Takes the parameters in front of a `=>` back off the operators, either a
+/// single identifier or a parenthesized list like `(acc, x)`.
+fn lambda_params(operators: &mut Vec<Operator>) -> Result<Vec<String>, Error> {
+    match operators.pop() {
+        Some(Operator::Identifier(ref ident)) if parse_number(ident).is_none() => {
+            Ok(vec![ident.clone()])
+        }
+        Some(Operator::RightParenthesis) => {
+            let mut params = Vec::new();
+            let mut expect_param = true;
+            loop {
+                match (operators.pop(), expect_param) {
+                    (Some(Operator::LeftParenthesis), _) if params.is_empty() || !expect_param => break,
+                    (Some(Operator::Identifier(ident)), true) => params.push(ident),
+                    (Some(Operator::Comma), false) => (),
+                    _ => return Err(Error::InvalidLambda),
+                }
+                expect_param = !expect_param;
+            }
+            params.reverse();
+            Ok(params)
+        }
+        _ => Err(Error::InvalidLambda),
+    }
+}
+
+/// Byte offset where a lambda body ends, at the first `,` or closing bracket
+/// of the enclosing call, skipping nested brackets and quoted strings.
+fn body_end(body: &str) -> usize {
+    let mut depth = 0;
+    let mut quote = None;
+    for (offset, c) in body.char_indices() {
+        match c {
+            '\'' | '"' if quote.is_none() => quote = Some(c),
+            _ if quote == Some(c) => quote = None,
+            _ if quote.is_some() => (),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' | ',' if depth == 0 => return offset,
+            ')' | ']' | '}' => depth -= 1,
+            _ => (),
+        }
+    }
+    body.len()
+}
+
+/// Byte offset of the bracket closing a literal, skipping nested brackets and
+/// quoted strings.
+fn closing_bracket(raw: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (offset, c) in raw.char_indices() {
+        match c {
+            '\'' | '"' if quote.is_none() => quote = Some(c),
+            _ if quote == Some(c) => quote = None,
+            _ if quote.is_some() => (),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth == 0 => return Some(offset),
+            ')' | ']' | '}' => depth -= 1,
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Splits on top-level commas, a trailing comma leaves no empty entry.
+fn split_entries(raw: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut rest = raw;
+    while !rest.trim().is_empty() {
+        let end = body_end(rest);
+        entries.push(&rest[..end]);
+        rest = if end < rest.len() { &rest[end + 1..] } else { "" };
+    }
+    entries
+}
+
+/// Parses the inside of `[1, x + 1, ...rest]`, each item is parsed as its own tree.
+fn parse_array(raw: &str) -> Result<Operator, Error> {
+    let mut items = Vec::new();
+    for item in split_entries(raw) {
+        items.push(parse_expression(item)?);
+    }
+    Ok(Operator::Array(items))
+}
+
+/// Parses the inside of `{ nama: user.nama, 'total harga': qty * harga }`, each
+/// value is parsed as its own tree.
+fn parse_object(raw: &str) -> Result<Operator, Error> {
+    let mut entries = Vec::new();
+    for entry in split_entries(raw) {
+        let invalid = || Error::InvalidObjectLiteral(entry.trim().to_owned());
+        let colon = entry.find(':').ok_or_else(invalid)?;
+        let key = entry[..colon].trim();
+        let key = match key.chars().next() {
+            Some(quote @ '\'') | Some(quote @ '"') if key.len() > 1 && key.ends_with(quote) => {
+                &key[1..key.len() - 1]
+            }
+            Some(_) if key.chars().all(|c| c.is_alphanumeric() || c == '_') => key,
+            _ => return Err(invalid()),
+        };
+        entries.push((key.to_owned(), parse_expression(&entry[colon + 1..])?));
+    }
+    Ok(Operator::Object(entries))
+}
+
+/// Splits a double quoted literal like `"Halo, ${user.nama}!"` into literal text
+/// and sub-expressions, each `${...}` is parsed as its own tree.
+fn parse_template(raw: &str) -> Result<Operator, Error> {
+    let mut parts = Vec::new();
+    let mut rest = raw;
+    while let Some(start) = rest.find("${") {
+        if start > 0 {
+            parts.push(Operator::Value(to_value(&rest[..start])).to_node());
+        }
+
+        let expression = &rest[start + 2..];
+        let end = template_end(expression).ok_or(Error::UnpairedBrackets)?;
+        parts.push(parse_expression(&expression[..end])?);
+        rest = &expression[end + 1..];
+    }
+
+    if !rest.is_empty() {
+        parts.push(Operator::Value(to_value(rest)).to_node());
+    }
+    Ok(Operator::Template(parts))
+}
+
+/// Byte offset of the `}` closing a `${`, skipping braces in nested single quoted strings.
+fn template_end(expression: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quoted = false;
+    for (offset, c) in expression.char_indices() {
+        match c {
+            '\'' => quoted = !quoted,
+            '{' if !quoted => depth += 1,
+            '}' if !quoted && depth == 0 => return Some(offset),
+            '}' if !quoted => depth -= 1,
+            _ => (),
+        }
+    }
+    None
+}
+
+fn rewrite_pipeline(mut node: Node) -> Result<Node, Error> {
+    let mut children = Vec::new();
+    for child in node.children {
+        children.push(rewrite_pipeline(child)?);
+    }
+    node.children = children;
+
+    match node.operator {
+        Operator::Pipe(_) => {
+            if node.children.len() != 2 {
+                return Err(Error::InvalidPipeline);
+            }
+            let mut function = node.moveout_last_node();
+            let argument = node.moveout_last_node();
+            match function.operator {
+                Operator::Identifier(ident) if parse_number(&ident).is_none() &&
+                                               !is_range(&ident) => {
+                    function = Operator::Function(ident).to_node();
+                    function.closed = true;
+                }
+                Operator::Function(_) => (),
+                _ => return Err(Error::InvalidPipeline),
+            }
+            function.children.insert(0, argument);
+            Ok(function)
+        }
+        _ => Ok(node),
+    }
+}
+
 fn get_final_node(mut parsing_nodes: Vec<Node>) -> Result<Node, Error> {
     if parsing_nodes.is_empty() {
         return Err(Error::NoFinalNode);
@@ -505,14 +1019,46 @@ fn get_final_node(mut parsing_nodes: Vec<Node>) -> Result<Node, Error> {
     Ok(parsing_nodes.pop().unwrap())
 }
 
+fn after_member_access(operators: &[Operator]) -> bool {
+    operators.last().map(|operator| operator.is_dot() || operator.is_optional_dot()).unwrap_or(false)
+}
+
+/// Whether the text after a prefix keyword starts its operand, `teks[0]` indexes
+/// while `teks [1]` casts an array literal. Binary operators, `-` included, make
+/// the keyword a name, so `bulat - 1` subtracts from a variable.
+fn operand_follows(rest: &str) -> bool {
+    let trimmed = rest.trim_start();
+    match trimmed.chars().next() {
+        None => false,
+        Some('[') => trimmed.len() < rest.len(),
+        Some(c) if ")]},:?.+-*/%=<>!&|^".contains(c) => false,
+        _ => !["in", "dan", "atau"].iter().any(|word| {
+            trimmed.starts_with(word) &&
+            !trimmed[word.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+        }),
+    }
+}
+
+/// Prefix operators like `-` or `!` left without an operand, e.g. `2 * -`.
+fn check_operands(node: &Node) -> Result<(), Error> {
+    if node.operator.is_unary() && node.children.is_empty() {
+        return Err(Error::StartWithNonValueOperator);
+    }
+
+    for child in &node.children {
+        check_operands(child)?;
+    }
+    Ok(())
+}
+
 fn close_bracket(parsing_nodes: &mut Vec<Node>, bracket: Operator) -> Result<(), Error> {
     loop {
         let mut current = parsing_nodes.pop().unwrap();
         let mut prev = parsing_nodes.pop().unwrap();
 
-        if current.operator.is_left_square_bracket() {
+        if current.is_unclosed_square_bracket() {
             return Err(Error::BracketNotWithFunction);
-        } else if prev.operator.is_left_square_bracket() {
+        } else if prev.is_unclosed_square_bracket() {
             prev.add_child(current);
             prev.closed = true;
             parsing_nodes.push(prev);
@@ -565,6 +1111,41 @@ fn close_bracket(parsing_nodes: &mut Vec<Node>, bracket: Operator) -> Result<(),
     Ok(())
 }
 
+fn close_scope(brackets: &mut Vec<Operator>, ternaries: &[usize]) -> Result<(), Error> {
+    if ternaries.last() == Some(&brackets.len()) {
+        return Err(Error::UnpairedTernary);
+    }
+
+    brackets.pop();
+    Ok(())
+}
+
+fn start_slice(parsing_nodes: &mut Vec<Node>) -> Result<(), Error> {
+    let mut slice = Operator::Slice(1).to_node();
+
+    if parsing_nodes.last().map(Node::is_unclosed_square_bracket).unwrap_or(false) {
+        slice.add_child(Operator::Value(Value::Null).to_node());
+    } else {
+        let mut current = parsing_nodes.pop().ok_or(Error::CanNotAddChild)?;
+        loop {
+            let mut prev = parsing_nodes.pop().ok_or(Error::CanNotAddChild)?;
+            if prev.is_unclosed_square_bracket() {
+                parsing_nodes.push(prev);
+                break;
+            } else if prev.is_waiting_operand() {
+                prev.add_child(current);
+                current = prev;
+            } else {
+                return Err(Error::CanNotAddChild);
+            }
+        }
+        slice.add_child(current);
+    }
+
+    parsing_nodes.push(slice);
+    Ok(())
+}
+
 fn close_comma(parsing_nodes: &mut Vec<Node>) -> Result<(), Error> {
     if parsing_nodes.len() < 2 {
         return Err(Error::CommaNotWithFunction);
@@ -577,11 +1158,11 @@ fn close_comma(parsing_nodes: &mut Vec<Node>) -> Result<(), Error> {
         if current.operator == Operator::Comma {
             parsing_nodes.push(prev);
             break;
-        } else if current.operator.is_left() {
+        } else if current.operator.is_left() && !current.closed {
             parsing_nodes.push(prev);
             parsing_nodes.push(current);
             break;
-        } else if prev.operator.is_left() {
+        } else if prev.operator.is_left() && !prev.closed {
             if let Some(mut penult) = parsing_nodes.pop() {
                 if penult.is_unclosed_function() {
                     penult.add_child(current);
@@ -612,12 +1193,139 @@ fn close_comma(parsing_nodes: &mut Vec<Node>) -> Result<(), Error> {
     Ok(())
 }
 
+fn attach_to_waiting(parsing_nodes: &mut Vec<Node>, operator: &Operator, priority: u8) {
+    while parsing_nodes.len() > 1 {
+        let last_is_full = parsing_nodes.last().unwrap().is_value_or_full_children();
+        let penult = &parsing_nodes[parsing_nodes.len() - 2];
+        let penult_priority = penult.operator.get_priority();
+        if last_is_full && penult.is_waiting_operand() &&
+           (penult_priority > priority ||
+            penult_priority == priority && !operator.is_right_associative()) {
+            let last = parsing_nodes.pop().unwrap();
+            parsing_nodes.last_mut().unwrap().add_child(last);
+        } else {
+            break;
+        }
+    }
+}
+
+fn check_function_args(function: &Function, args_length: usize) -> Result<(), Error> {
+    if let Some(len) = function.max_args {
+        if args_length > len {
+            return Err(Error::ArgumentsGreater(len));
+        }
+    }
+
+    if let Some(len) = function.min_args {
+        if args_length < len {
+            return Err(Error::ArgumentsLess(len));
+        }
+    }
+
+    Ok(())
+}
+
 fn rob_to(mut was_robed: Node, mut rober: Node) -> Vec<Node> {
     let moveout_node = was_robed.moveout_last_node();
     rober.add_child(moveout_node);
     vec![was_robed, rober]
 }
 
+fn compound_width(chars: &[char]) -> usize {
+    match chars {
+        ['.', '.', '.', ..] => 3,
+        ['*', '*', ..] |
+        ['/', '/', ..] |
+        ['&', '&', ..] |
+        ['|', '|', ..] |
+        ['|', '>', ..] |
+        ['=', '>', ..] |
+        ['<', '<', ..] |
+        ['>', '>', ..] => 2,
+        ['?', '.', next, ..] if !next.is_ascii_digit() => 2,
+        ['?', '.'] => 2,
+        _ => 1,
+    }
+}
+
+/// Whether the `+` or `-` at `index` is the exponent sign of a literal like `2E-3`.
+fn is_exponent_sign(chars: &[char], index: usize) -> bool {
+    if chars[index] != '+' && chars[index] != '-' {
+        return false;
+    }
+
+    let start = chars[..index]
+        .iter()
+        .rposition(|c| !c.is_ascii_alphanumeric() && *c != '_')
+        .map_or(0, |position| position + 1);
+    match chars[start..index].split_last() {
+        Some((&'e', mantissa)) | Some((&'E', mantissa)) => {
+            !mantissa.is_empty() && mantissa.iter().all(|c| c.is_ascii_digit() || *c == '_') &&
+            mantissa[0].is_ascii_digit() &&
+            chars.get(index + 1).is_some_and(char::is_ascii_digit)
+        }
+        _ => false,
+    }
+}
+
+/// `value[start:end]`, shared with the `iris` builtin.
+pub(crate) fn slice(value: &Value, start: &Value, end: &Value) -> Result<Value, Error> {
+    match *value {
+        Value::Array(ref array) => {
+            let (start, end) = slice_bounds(start, end, array.len())?;
+            Ok(to_value(&array[start..end]))
+        }
+        Value::String(ref string) => {
+            let chars = string.chars().collect::<Vec<_>>();
+            let (start, end) = slice_bounds(start, end, chars.len())?;
+            Ok(to_value(chars[start..end].iter().collect::<String>()))
+        }
+        Value::Null => Ok(Value::Null),
+        _ => Err(Error::ExpectedArray),
+    }
+}
+
+fn slice_bounds(start: &Value, end: &Value, len: usize) -> Result<(usize, usize), Error> {
+    let start = if start.is_null() { 0 } else { slice_index(start, len)? };
+    let end = if end.is_null() { len } else { slice_index(end, len)? };
+    Ok((start, start.max(end)))
+}
+
+fn slice_index(index: &Value, len: usize) -> Result<usize, Error> {
+    match index.as_i64() {
+        Some(index) if index < 0 => Ok(len.saturating_sub(index.unsigned_abs() as usize)),
+        Some(index) => Ok(len.min(index as usize)),
+        None if index.is_u64() => Ok(len),
+        None => Err(Error::ExpectedNumber),
+    }
+}
+
+fn array_index(index: &Value, len: usize) -> Option<usize> {
+    match index.as_u64() {
+        Some(index) => Some(index as usize),
+        None => {
+            let back = index.as_i64()?.unsigned_abs() as usize;
+            if back <= len { Some(len - back) } else { None }
+        }
+    }
+}
+
+/// Contexts an expression reads, a lambda's parameters are a layer on top of
+/// the caller's scope instead of a copy of it.
+enum Scope<'a> {
+    Contexts(&'a [Context]),
+    Layer(&'a Context, &'a Scope<'a>),
+}
+
+impl<'a> Scope<'a> {
+    fn find(&self, key: &str) -> Option<Value> {
+        match *self {
+            Scope::Contexts(contexts) => find(contexts, key),
+            Scope::Layer(layer, parent) => layer.get(key).cloned().or_else(|| parent.find(key)),
+        }
+    }
+}
+
 fn find(contexts: &[Context], key: &str) -> Option<Value> {
     for context in contexts.iter().rev() {
         match context.get(key) {
@@ -635,25 +1343,44 @@ fn is_range(ident: &str) -> bool {
 
 fn parse_range(ident: &str) -> Result<Value, Error> {
     let segments = ident.split("..").collect::<Vec<_>>();
-    if segments.len() != 2 {
-        Err(Error::InvalidRange(ident.to_owned()))
-    } else {
-        let start = segments[0].parse::<i64>();
-        let end = segments[1].parse::<i64>();
+    if segments.len() != 2 && segments.len() != 3 {
+        return Err(Error::InvalidRange(ident.to_owned()));
+    }
 
-        if start.is_ok() && end.is_ok() {
-            let mut array = Vec::new();
-            for n in start.unwrap()..end.unwrap() {
-                array.push(n);
-            }
+    let inclusive = segments[1].starts_with('=');
+    let start = segments[0].parse::<i64>();
+    let end = segments[1].trim_start_matches('=').parse::<i64>();
+    let step = segments.get(2).map_or(Ok(1), |step| step.parse::<usize>());
+
+    match (start, end, step) {
+        (Ok(start), Ok(end), Ok(step)) if step > 0 => {
+            let array = if inclusive {
+                (start..=end).step_by(step).collect::<Vec<_>>()
+            } else {
+                (start..end).step_by(step).collect::<Vec<_>>()
+            };
             Ok(to_value(array))
-        } else {
-            Err(Error::InvalidRange(ident.to_owned()))
         }
+        _ => Err(Error::InvalidRange(ident.to_owned())),
     }
 }
 
 fn parse_number(ident: &str) -> Option<Value> {
+    if !ident.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+
+    let ident = &ident.replace('_', "");
+    let radix = match ident.get(..2) {
+        Some("0x") | Some("0X") => 16,
+        Some("0o") | Some("0O") => 8,
+        Some("0b") | Some("0B") => 2,
+        _ => 10,
+    };
+    if radix != 10 {
+        return u64::from_str_radix(&ident[2..], radix).ok().map(to_value);
+    }
+
     let number = ident.parse::<u64>();
     if number.is_ok() {
         return Some(to_value(number.unwrap()));
@@ -664,6 +1391,13 @@ fn parse_number(ident: &str) -> Option<Value> {
         return Some(to_value(number.unwrap()));
     }
 
+    #[cfg(feature = "bigint")]
+    {
+        if ident.bytes().all(|byte| byte.is_ascii_digit()) {
+            return ident.parse().ok().map(big_value);
+        }
+    }
+
     let number = ident.parse::<f64>();
     if number.is_ok() {
         return Some(to_value(number.unwrap()));
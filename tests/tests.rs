@@ -134,6 +134,52 @@ mod tests {
         assert_eq!(eval(r#""Hello"+", world!""#), Ok(to_value("Hello, world!")));
     }
 
+    #[test]
+    fn test_array_add() {
+        assert_eq!(
+            eval("untaian(1, 2) + untaian(3, 4)"),
+            Ok(to_value(vec![1, 2, 3, 4]))
+        );
+        assert_eq!(eval("untaian(1, 2) + 3"), Ok(to_value(vec![1, 2, 3])));
+    }
+
+    #[test]
+    fn test_string_mul() {
+        assert_eq!(eval("'ab' * 3"), Ok(to_value("ababab")));
+        assert_eq!(eval("2 * 'ab'"), Ok(to_value("abab")));
+        assert_eq!(eval("'ab' * 0"), Ok(to_value("")));
+        assert_eq!(eval("'ab' * 10000000000000"), Err(Error::TooLarge));
+        assert_eq!(eval("untaian(1) * 18446744073709551615"), Err(Error::TooLarge));
+        assert_eq!(eval("'' * 10000000000000"), Ok(to_value("")));
+    }
+
+    #[test]
+    fn test_array_mul() {
+        assert_eq!(eval("untaian(0) * 3"), Ok(to_value(vec![0, 0, 0])));
+        assert_eq!(eval("untaian(1, 2) * 2"), Ok(to_value(vec![1, 2, 1, 2])));
+        assert_eq!(eval("'ab' * 1.5"),
+                   Err(Error::UnsupportedTypes(format!("{:?}", to_value("ab")),
+                                               format!("{:?}", to_value(1.5)))));
+    }
+
+    #[test]
+    fn test_object_add() {
+        let mut a = HashMap::new();
+        a.insert("nama", "Budi");
+        a.insert("kota", "Bandung");
+        let mut b = HashMap::new();
+        b.insert("kota", "Jakarta");
+        b.insert("umur", "17");
+        let mut merged = HashMap::new();
+        merged.insert("nama", "Budi");
+        merged.insert("kota", "Jakarta");
+        merged.insert("umur", "17");
+        assert_eq!(
+            Expr::new("a + b").value("a", a).value("b", b).exec(),
+            Ok(to_value(merged))
+        );
+    }
+
     #[test]
     fn test_equal() {
         assert_eq!(eval("1 == 1"), Ok(to_value(true)));
@@ -227,11 +273,1353 @@ mod tests {
         assert_eq!(eval("0..5"), Ok(to_value(vec![0, 1, 2, 3, 4])));
     }
 
+    #[test]
+    fn test_range_with_step() {
+        assert_eq!(eval("0..10..2"), Ok(to_value(vec![0, 2, 4, 6, 8])));
+        assert_eq!(eval("0..10..0"), Err(Error::InvalidRange("0..10..0".to_owned())));
+    }
+
+    #[test]
+    fn test_range_inclusive() {
+        assert_eq!(eval("0..=5"), Ok(to_value(vec![0, 1, 2, 3, 4, 5])));
+        assert_eq!(eval("0..=10..5"), Ok(to_value(vec![0, 5, 10])));
+    }
+
     #[test]
     fn test_range_and_min() {
         assert_eq!(eval("min(0..5)"), Ok(to_value(0)));
     }
 
+    #[test]
+    fn test_mixed_number_compare() {
+        assert_eq!(eval("1 == 1.0"), Ok(to_value(true)));
+        assert_eq!(eval("2.5 > 2"), Ok(to_value(true)));
+        assert_eq!(eval("-1 <= 0.5"), Ok(to_value(true)));
+        assert_eq!(eval("3 != 3.0"), Ok(to_value(false)));
+        assert_eq!(
+            Expr::new("a == b")
+                .value("a", 9007199254740993u64)
+                .value("b", 9007199254740992u64)
+                .exec(),
+            Ok(to_value(false))
+        );
+    }
+
+    #[test]
+    fn test_deep_equality() {
+        assert_eq!(eval("untaian(1, 2) == untaian(1, 2)"), Ok(to_value(true)));
+        assert_eq!(eval("untaian(1, 2) == untaian(1.0, 2)"), Ok(to_value(true)));
+        assert_eq!(eval("untaian(1, 2) != untaian(2, 1)"), Ok(to_value(true)));
+
+        let mut object = HashMap::new();
+        object.insert("a", to_value(vec![1, 2]));
+        object.insert("b", to_value("x"));
+        let mut other = HashMap::new();
+        other.insert("b", to_value("x"));
+        other.insert("a", to_value(vec![1.0, 2.0]));
+        assert_eq!(
+            Expr::new("object == other")
+                .value("object", object)
+                .value("other", other.clone())
+                .exec(),
+            Ok(to_value(true))
+        );
+        other.insert("c", to_value(true));
+        assert_eq!(
+            Expr::new("object != other")
+                .value("object", HashMap::<&str, Value>::new())
+                .value("other", other)
+                .exec(),
+            Ok(to_value(true))
+        );
+    }
+
+    #[test]
+    fn test_cast() {
+        assert_eq!(
+            Expr::new("int umur + 1 > 17 dan float harga * 2 == 25")
+                .value("umur", "17")
+                .value("harga", " 12.5 ")
+                .exec(),
+            Ok(to_value(true))
+        );
+        assert_eq!(eval("bulat '12.7'"), Ok(to_value(12)));
+        assert_eq!(eval("pecahan 3"), Ok(to_value(3.0)));
+        assert_eq!(eval("str 5 + teks true"), Ok(to_value("5true")));
+        assert_eq!(eval("int 'abc'"),
+                   Err(Error::CanNotCast(to_value("abc"), "int".to_owned())));
+        assert_eq!(eval("int 1e30"), Err(Error::CanNotCast(to_value(1e30), "int".to_owned())));
+        assert_eq!(eval("ke_bulat('1e30')"), Err(Error::CanNotCast(to_value("1e30"), "int".to_owned())));
+        assert_eq!(eval("int(-9.2e18)"), Ok(to_value(-9200000000000000000i64)));
+    }
+
+    #[test]
+    fn test_keywords_as_field_names() {
+        let json = |source: &str| serde_json::from_str::<Value>(source).unwrap();
+        let user = json(r#"{"in": 1, "dan": 2, "bukan": 3, "benar": 4}"#);
+        let expr = |source: &str| Expr::new(source).value("user", &user).exec();
+        assert_eq!(expr("user.in"), Ok(to_value(1)));
+        assert_eq!(expr("user?.in + user.dan"), Ok(to_value(3)));
+        assert_eq!(expr("user.bukan * user.benar"), Ok(to_value(12)));
+        assert_eq!(expr("user.in in [1]"), Ok(to_value(true)));
+    }
+
+    #[test]
+    fn test_cast_keywords_as_names() {
+        for keyword in &["int", "bulat", "float", "pecahan", "str", "teks"] {
+            assert_eq!(eval(keyword), Ok(Value::Null), "{}", keyword);
+        }
+        assert_eq!(eval("max(1, int)"), Ok(to_value(1)));
+        let mut user = HashMap::new();
+        user.insert("teks", "halo");
+        assert_eq!(Expr::new("user.teks + '!'").value("user", &user).exec(), Ok(to_value("halo!")));
+        assert_eq!(Expr::new("user?.teks").value("user", &user).exec(), Ok(to_value("halo")));
+        assert_eq!(Expr::new("int == 3 && int in [3]").value("int", 3).exec(), Ok(to_value(true)));
+        assert_eq!(eval("teks [1]"), Ok(to_value("[1]")));
+        assert_eq!(Expr::new("bulat - 1").value("bulat", 10).exec(), Ok(to_value(9)));
+        assert_eq!(Expr::new("pecahan + 1").value("pecahan", 0.5).exec(), Ok(to_value(1.5)));
+        assert_eq!(Expr::new("teks -1 == 9").value("teks", 10).exec(), Ok(to_value(true)));
+        assert_eq!(eval("bulat(-2.5)"), Ok(to_value(-2)));
+    }
+
+    #[test]
+    fn test_string_case() {
+        assert_eq!(eval("huruf_besar('Jalan Ñandú')"), Ok(to_value("JALAN ÑANDÚ")));
+        assert_eq!(eval("lower('ÀBC') == huruf_kecil('àbc')"), Ok(to_value(true)));
+        assert_eq!(eval("upper(1)"), Err(Error::ExpectedString));
+    }
+
+    #[test]
+    fn test_trim() {
+        assert_eq!(
+            Expr::new("pangkas(nama) == 'Budi'").value("nama", "  Budi\n").exec(),
+            Ok(to_value(true))
+        );
+        assert_eq!(eval("pangkas_kiri('  a ')"), Ok(to_value("a ")));
+        assert_eq!(eval("pangkas_kanan('  a ')"), Ok(to_value("  a")));
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(eval("pisah('a,b,c', ',')"), Ok(to_value(vec!["a", "b", "c"])));
+        assert_eq!(eval("pisah('a,b,c', ',', 1)"), Ok(to_value(vec!["a", "b,c"])));
+        assert_eq!(eval("pisah('a,b', ',', 18446744073709551615)"), Ok(to_value(vec!["a", "b"])));
+        assert_eq!(eval("panjang(pisah('a,b,c', ',')) + 1"), Ok(to_value(4)));
+        assert_eq!(eval("pisah('a,b,c', ',')[-1]"), Ok(to_value("c")));
+    }
+
+    #[test]
+    fn test_join() {
+        assert_eq!(eval("gabung(untaian('a', 'b', 'c'), '-')"), Ok(to_value("a-b-c")));
+        assert_eq!(eval("gabung(untaian('x', 1, 2.5, true), ', ')"),
+                   Ok(to_value("x, 1, 2.5, true")));
+        assert_eq!(eval("gabung(pisah('a,b', ','), ';')"), Ok(to_value("a;b")));
+    }
+
+    #[test]
+    fn test_replace() {
+        assert_eq!(eval("ganti('a.b.c', '.', '/')"), Ok(to_value("a/b/c")));
+        assert_eq!(eval("ganti('halo', 'x', 'y')"), Ok(to_value("halo")));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_replace_regex() {
+        assert_eq!(eval("ganti_regex('a1b22c', '[0-9]+', '#')"), Ok(to_value("a#b#c")));
+        match eval("ganti_regex('a', '(', '')") {
+            Err(Error::InvalidRegex(_)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_substring() {
+        assert_eq!(eval("potong('Selamat pagi', 8, 4)"), Ok(to_value("pagi")));
+        assert_eq!(eval("potong('héllo', 1, 3)"), Ok(to_value("éll")));
+        assert_eq!(eval("potong('héllo', -2)"), Ok(to_value("lo")));
+        assert_eq!(eval("potong('abc', 5, 1)"), Ok(to_value("")));
+    }
+
+    #[test]
+    fn test_char_at() {
+        assert_eq!(eval("karakter('héllo', 1)"), Ok(to_value("é")));
+        assert_eq!(eval("karakter('abc', -1)"), Ok(to_value("c")));
+        assert_eq!(eval("karakter('abc', 3)"), Ok(Value::Null));
+    }
+
+    #[test]
+    fn test_contains() {
+        assert_eq!(eval("berisi('feature-beta', 'beta')"), Ok(to_value(true)));
+        assert_eq!(eval("berisi(untaian(1, 2, 3), 2.0)"), Ok(to_value(true)));
+        assert_eq!(eval("berisi(untaian('a'), 'b')"), Ok(to_value(false)));
+    }
+
+    #[test]
+    fn test_starts_and_ends_with() {
+        assert_eq!(eval("diawali('beta-user', 'beta')"), Ok(to_value(true)));
+        assert_eq!(eval("diakhiri('beta-user', 'beta')"), Ok(to_value(false)));
+        assert_eq!(eval("diakhiri(untaian(1, 2), 2)"), Ok(to_value(true)));
+        assert_eq!(eval("diawali(1, 'a')"), Err(Error::ExpectedString));
+    }
+
+    #[test]
+    fn test_index_of() {
+        assert_eq!(eval("posisi('héllo hello', 'llo')"), Ok(to_value(2)));
+        assert_eq!(eval("posisi_akhir('héllo hello', 'llo')"), Ok(to_value(8)));
+        assert_eq!(eval("posisi(untaian(1, 2, 1), 1)"), Ok(to_value(0)));
+        assert_eq!(eval("posisi_akhir(untaian(1, 2, 1), 1)"), Ok(to_value(2)));
+        assert_eq!(eval("posisi('abc', 'x')"), Ok(to_value(-1)));
+    }
+
+    #[test]
+    fn test_pad() {
+        assert_eq!(eval("'INV-' + isi_kiri(42, 5, '0')"), Ok(to_value("INV-00042")));
+        assert_eq!(eval("isi_kanan('ab', 5, '.-')"), Ok(to_value("ab.-.")));
+        assert_eq!(eval("isi_kiri('é', 3)"), Ok(to_value("  é")));
+        assert_eq!(eval("isi_kiri('abcdef', 3)"), Ok(to_value("abcdef")));
+        assert_eq!(eval("isi_kanan('ab', 10000000000000)"), Err(Error::TooLarge));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            Expr::new("format('%s punya %d item', nama, jumlah)")
+                .value("nama", "Budi")
+                .value("jumlah", 3.9)
+                .exec(),
+            Ok(to_value("Budi punya 3 item"))
+        );
+        assert_eq!(eval("format('{} + {} = {}, 100%%', 1, 2.5, untaian(3.5))"),
+                   Ok(to_value("1 + 2.5 = [3.5], 100%")));
+        assert_eq!(eval("format('{{}}')"), Ok(to_value("{}")));
+        assert_eq!(eval("format('%s')"),
+                   Err(Error::Custom("Not enough arguments for format.".to_owned())));
+    }
+
+    #[test]
+    fn test_string_interpolation() {
+        let mut user = HashMap::new();
+        user.insert("nama", to_value("Sri"));
+        user.insert("umur", to_value(30));
+        assert_eq!(
+            Expr::new("\"Halo, ${user.nama}! Tahun depan ${user.umur + 1}.${kosong}\"")
+                .value("user", user)
+                .exec(),
+            Ok(to_value("Halo, Sri! Tahun depan 31."))
+        );
+        assert_eq!(eval("\"${format('{}', 1)}\""), Ok(to_value("1")));
+        assert_eq!(eval("'${literal}'"), Ok(to_value("${literal}")));
+        assert_eq!(eval("\"${tidak_tutup\""), Err(Error::UnpairedBrackets));
+    }
+
+    #[test]
+    fn test_reverse_and_repeat() {
+        assert_eq!(eval("balik_teks('héllo')"), Ok(to_value("olléh")));
+        assert_eq!(eval("ulang('ab', 3)"), Ok(to_value("ababab")));
+        assert_eq!(eval("ulang(untaian(1), 2)"), Ok(to_value(vec![1, 1])));
+        assert_eq!(eval("ulang(3, 2)"), Err(Error::ExpectedString));
+        assert_eq!(eval("ulang('ab', 10000000000000)"), Err(Error::TooLarge));
+        assert_eq!(eval("ulang(untaian(1, 2), 9000000000000000000)"), Err(Error::TooLarge));
+    }
+
+    #[test]
+    fn test_capitalize_and_title() {
+        assert_eq!(eval("kapital('élodie mARTIN')"), Ok(to_value("Élodie mARTIN")));
+        assert_eq!(eval("judul('élodie mARTIN  jr')"), Ok(to_value("Élodie Martin  Jr")));
+        assert_eq!(eval("kapital('')"), Ok(to_value("")));
+    }
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(eval("slug('Halo Dunia!') == 'halo-dunia'"), Ok(to_value(true)));
+        assert_eq!(eval("slug('  Crème Brûlée -- à la Straße ')"),
+                   Ok(to_value("creme-brulee-a-la-strasse")));
+        assert_eq!(eval("slug('Versi 2.0')"), Ok(to_value("versi-2-0")));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex_match_and_capture() {
+        assert_eq!(eval("cocok('ID-2024', '^ID-[0-9]+$')"), Ok(to_value(true)));
+        assert_eq!(eval("tangkap('2024-05', '([0-9]+)-([0-9]+)(x)?')"),
+                   Ok(to_value(vec![Some("2024"), Some("05"), None])));
+        assert_eq!(eval("tangkap('abc', '[0-9]')"), Ok(Value::Null));
+        assert_eq!(eval("cari_semua('a1b22c333', '[0-9]+')"),
+                   Ok(to_value(vec!["1", "22", "333"])));
+    }
+
+    #[test]
+    fn test_len_counts_chars() {
+        assert_eq!(eval("panjang('é')"), Ok(to_value(1)));
+        assert_eq!(eval("panjang('halo dunia')"), Ok(to_value(10)));
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_grapheme_len() {
+        assert_eq!(eval("panjang_grafem('e\u{301}🇮🇩')"), Ok(to_value(2)));
+        assert_eq!(eval("panjang('e\u{301}🇮🇩')"), Ok(to_value(4)));
+    }
+
+    #[test]
+    fn test_compare_builtins() {
+        assert_eq!(eval("banding('a', 'b')"), Ok(to_value(-1)));
+        assert_eq!(eval("banding(2, 2.0)"), Ok(to_value(0)));
+        assert_eq!(eval("banding('item10', 'item2')"), Ok(to_value(-1)));
+        assert_eq!(eval("banding_alami('item10', 'item2')"), Ok(to_value(1)));
+        assert_eq!(eval("banding_alami('v1.10.0', 'v1.9.3')"), Ok(to_value(1)));
+        assert_eq!(eval("banding_alami('a01', 'a1')"), Ok(to_value(1)));
+        assert_eq!(eval("banding_alami('x', 'x')"), Ok(to_value(0)));
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(eval("ke_json(untaian(1, 'a', nihil))"), Ok(to_value("[1,\"a\",null]")));
+        assert_eq!(
+            Expr::new("dari_json(kolom).harga * 2").value("kolom", "{\"harga\": 1.5}").exec(),
+            Ok(to_value(3.0))
+        );
+        assert_eq!(eval("dari_json(ke_json(untaian(1, 2)))[1]"), Ok(to_value(2)));
+        match eval("dari_json('{')") {
+            Err(Error::InvalidJson(_)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_url_encoding() {
+        assert_eq!(
+            Expr::new("'https://contoh.id/?next=' + url_enkode(tujuan)")
+                .value("tujuan", "/cari?q=kopi susu&kota=Bandung")
+                .exec(),
+            Ok(to_value("https://contoh.id/?next=%2Fcari%3Fq%3Dkopi%20susu%26kota%3DBandung"))
+        );
+        assert_eq!(eval("url_dekode(url_enkode('héllo/ü'))"), Ok(to_value("héllo/ü")));
+        assert_eq!(eval("url_dekode('100%')"),
+                   Err(Error::InvalidEncoding("malformed percent-encoding: 100%".to_owned())));
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn test_base64() {
+        assert_eq!(eval("base64_enkode('halo dunia')"), Ok(to_value("aGFsbyBkdW5pYQ==")));
+        assert_eq!(eval("base64_dekode('aGFsbyBkdW5pYQ==')"), Ok(to_value("halo dunia")));
+        match eval("base64_dekode('***')") {
+            Err(Error::InvalidEncoding(_)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "hex")]
+    #[test]
+    fn test_hex() {
+        assert_eq!(eval("hex_enkode('Hi!')"), Ok(to_value("486921")));
+        assert_eq!(eval("hex_dekode('486921')"), Ok(to_value("Hi!")));
+        match eval("hex_dekode('4')") {
+            Err(Error::InvalidEncoding(_)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn test_hash() {
+        assert_eq!(eval("hash_md5('abc')"), Ok(to_value("900150983cd24fb0d6963f7d28e17f72")));
+        assert_eq!(eval("hash_sha1('abc')"),
+                   Ok(to_value("a9993e364706816aba3e25717850c26c9cd0d89d")));
+        assert_eq!(eval("hash_sha256('abc')"),
+                   Ok(to_value("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")));
+        assert_eq!(
+            Expr::new("hash_sha256(user + 'exp1') == hash_sha256('u-1exp1')")
+                .value("user", "u-1")
+                .exec(),
+            Ok(to_value(true))
+        );
+
+        // `ba7816bf8f01cfea` is 13436514500253700074
+        assert_eq!(eval("hash_bucket('abc', 100)"), Ok(to_value(74)));
+        assert_eq!(eval("hash_bucket('abc', 1)"), Ok(to_value(0)));
+        assert_eq!(eval("hash_bucket('abc', 0)"), Err(Error::DivisionByZero));
+        let mut contexts = vec![Context::new()];
+        let mut in_rollout = 0;
+        let expr = Expr::new("hash_bucket(user + 'exp1', 100) < 10").compile().unwrap();
+        for id in 0..1000 {
+            contexts[0].insert("user".to_owned(), to_value(format!("u-{}", id)));
+            if ExecOptions::new(&expr).contexts(&contexts).exec() == Ok(to_value(true)) {
+                in_rollout += 1;
+            }
+        }
+        assert!(in_rollout > 50 && in_rollout < 150, "{}", in_rollout);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid() {
+        assert_eq!(eval("panjang(uuid()) == 36 && uuid() != uuid()"), Ok(to_value(true)));
+        assert_eq!(eval("karakter(uuid(), 14)"), Ok(to_value("4")));
+        assert_eq!(
+            Expr::new("uuid_v5('dns', 'contoh.id') == uuid_v5(namespace, 'contoh.id')")
+                .value("namespace", "6ba7b810-9dad-11d1-80b4-00c04fd430c8")
+                .exec(),
+            Ok(to_value(true))
+        );
+        assert_eq!(eval("uuid_v5('url', 'a') != uuid_v5('url', 'b')"), Ok(to_value(true)));
+    }
+
+    #[test]
+    fn test_abs_and_sign() {
+        assert_eq!(eval("mutlak(-5)"), Ok(to_value(5)));
+        assert_eq!(eval("mutlak(-9223372036854775807 - 1)"), Ok(to_value(9223372036854775808u64)));
+        assert_eq!(eval("mutlak(-2.5)"), Ok(to_value(2.5)));
+        assert_eq!(eval("tanda(-0.1) + tanda(0) + tanda(7)"), Ok(to_value(0)));
+        assert_eq!(eval("mutlak('a')"), Err(Error::ExpectedNumber));
+    }
+
+    #[test]
+    fn test_floor_ceil_round() {
+        assert_eq!(eval("lantai(2.7)"), Ok(to_value(2)));
+        assert_eq!(eval("lantai(-2.1)"), Ok(to_value(-3)));
+        assert_eq!(eval("langit(2.1)"), Ok(to_value(3)));
+        assert_eq!(eval("bulatkan(2.5)"), Ok(to_value(3)));
+        assert_eq!(eval("bulatkan(2.71828, 2)"), Ok(to_value(2.72)));
+        assert_eq!(eval("bulatkan(1234, -2)"), Ok(to_value(1200)));
+        assert_eq!(eval("bulatkan(0.125, 2)"), Ok(to_value(0.13)));
+        assert_eq!(eval("bulatkan(1e300, 300)"), Ok(to_value(1e300)));
+        assert_eq!(eval("bulatkan(2.5, 9223372036854775807)"), Ok(to_value(2.5)));
+    }
+
+    #[test]
+    fn test_sqrt_pow_exp_log() {
+        assert_eq!(eval("akar(16)"), Ok(to_value(4.0)));
+        assert_eq!(eval("pangkat(2, 10)"), Ok(to_value(1024)));
+        assert_eq!(eval("pangkat(4, 0.5)"), Ok(to_value(2.0)));
+        assert_eq!(eval("log(eksp(2))"), Ok(to_value(2.0)));
+        assert_eq!(eval("log10(1000) + log2(8)"), Ok(to_value(6.0)));
+        assert_eq!(eval("akar(-1)"), Err(Error::NotFinite));
+        assert_eq!(eval("log(0)"), Err(Error::NotFinite));
+    }
+
+    #[test]
+    fn test_trigonometry() {
+        assert_eq!(eval("sin(0)"), Ok(to_value(0.0)));
+        assert_eq!(eval("cos(0)"), Ok(to_value(1.0)));
+        assert_eq!(eval("bulatkan(derajat(atan2(1, 1)), 6)"), Ok(to_value(45.0)));
+        assert_eq!(eval("bulatkan(sin(radian(90)), 6)"), Ok(to_value(1.0)));
+        assert_eq!(eval("bulatkan(derajat(acos(0)) + derajat(asin(1)), 6)"), Ok(to_value(180.0)));
+        assert_eq!(eval("asin(2)"), Err(Error::NotFinite));
+        assert_eq!(eval("sin('a')"), Err(Error::ExpectedNumber));
+    }
+
+    #[test]
+    fn test_math_constants() {
+        assert_eq!(eval("pi()"), Ok(to_value(std::f64::consts::PI)));
+        assert_eq!(eval("log(e())"), Ok(to_value(1.0)));
+        assert_eq!(eval("bulatkan(cos(pi()), 6)"), Ok(to_value(-1.0)));
+        assert_eq!(eval("pi(1)"), Err(Error::ArgumentsGreater(0)));
+    }
+
+    #[test]
+    fn test_random() {
+        assert_eq!(eval("acak() >= 0 && acak() < 1"), Ok(to_value(true)));
+        assert_eq!(eval("acak_antara(1, 6) in untaian(1, 2, 3, 4, 5, 6)"), Ok(to_value(true)));
+        assert_eq!(eval("acak_antara(3, 3)"), Ok(to_value(3)));
+        assert!(eval("acak_antara(-9223372036854775808, 9223372036854775807)").unwrap().is_i64());
+        assert_eq!(eval("acak_pilih(untaian('a', 'a'))"), Ok(to_value("a")));
+        assert_eq!(eval("acak_pilih(untaian())"), Ok(Value::Null));
+        assert_eq!(eval("acak_antara(6, 1)"), Err(Error::InvalidRange("6..1".to_owned())));
+    }
+
+    #[test]
+    fn test_random_seed() {
+        let expr = Expr::new("untaian(acak(), acak_antara(1, 100), acak_pilih(untaian(1, 2, 3)))").compile().unwrap();
+        let first = ExecOptions::new(&expr).seed(42).exec().unwrap();
+        assert_eq!(ExecOptions::new(&expr).seed(42).exec(), Ok(first.clone()));
+        assert!(ExecOptions::new(&expr).seed(7).exec().unwrap() != first);
+        assert_eq!(ExecOptions::new(&Expr::new("acak() != acak()")).seed(1).exec(), Ok(to_value(true)));
+    }
+
+    #[test]
+    fn test_clamp_and_lerp() {
+        assert_eq!(eval("jepit(15, 0, 10)"), Ok(to_value(10)));
+        assert_eq!(eval("jepit(-2.5, 0, 10)"), Ok(to_value(0)));
+        assert_eq!(eval("jepit(4.5, 0, 10)"), Ok(to_value(4.5)));
+        assert_eq!(eval("jepit(1, 10, 0)"), Err(Error::InvalidRange("10..0".to_owned())));
+        assert_eq!(eval("lerp(10, 20, 0.25)"), Ok(to_value(12.5)));
+        assert_eq!(eval("lerp(0, 10, 1.5)"), Ok(to_value(15.0)));
+        assert_eq!(eval("lerp('a', 1, 0)"), Err(Error::ExpectedNumber));
+    }
+
+    #[test]
+    fn test_gcd_lcm_factorial() {
+        assert_eq!(eval("fpb(12, 18)"), Ok(to_value(6)));
+        assert_eq!(eval("fpb(-4, 0)"), Ok(to_value(4)));
+        assert_eq!(eval("kpk(4, 6)"), Ok(to_value(12)));
+        assert_eq!(eval("kpk(0, 5)"), Ok(to_value(0)));
+        assert_eq!(eval("kpk(9223372036854775807, 2)"),
+                   Err(Error::Overflow));
+        assert_eq!(eval("fpb(1.5, 2)"), Err(Error::ExpectedNumber));
+        assert_eq!(eval("faktorial(0)"), Ok(to_value(1)));
+        assert_eq!(eval("faktorial(20)"), Ok(to_value(2432902008176640000i64)));
+        assert_eq!(eval("faktorial(21)"), Err(Error::Overflow));
+        assert_eq!(eval("faktorial(-1)"),
+                   Err(Error::Custom("Factorial of a negative number: -1".to_owned())));
+    }
+
+    #[test]
+    fn test_statistics() {
+        assert_eq!(eval("rata(untaian(1, 2, 3, 4))"), Ok(to_value(2.5)));
+        assert_eq!(eval("median(untaian(3, 1, 2))"), Ok(to_value(2.0)));
+        assert_eq!(eval("median(untaian(4, 1, 3, 2))"), Ok(to_value(2.5)));
+        assert_eq!(eval("simpangan_baku(untaian(2, 4, 4, 4, 5, 5, 7, 9))"), Ok(to_value(2.0)));
+        assert_eq!(eval("persentil(untaian(10, 20, 30, 40, 50), 90)"), Ok(to_value(46.0)));
+        assert_eq!(eval("persentil(untaian(10, 20), 0)"), Ok(to_value(10.0)));
+        assert_eq!(eval("rata(untaian())"), Ok(Value::Null));
+        assert_eq!(eval("rata(untaian(1, 'a'))"), Err(Error::ExpectedNumber));
+        assert_eq!(eval("median(5)"), Err(Error::ExpectedArray));
+        assert_eq!(eval("persentil(untaian(1), 101)"),
+                   Err(Error::InvalidRange("persentil 101".to_owned())));
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        assert_eq!(eval("jumlah(untaian(1, 2, 3))"), Ok(to_value(6)));
+        assert_eq!(eval("jumlah(1, 2.5, untaian(3))"), Ok(to_value(6.5)));
+        assert_eq!(eval("jumlah(untaian())"), Ok(to_value(0)));
+        assert_eq!(eval("kali_semua(untaian(2, 3), 4)"), Ok(to_value(24)));
+        assert_eq!(eval("kali_semua(untaian())"), Ok(to_value(1)));
+        assert_eq!(eval("jumlah(1, '2')"), Err(Error::ExpectedNumber));
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    #[test]
+    fn test_integer_overflow() {
+        assert_eq!(eval("9223372036854775807 + 1"), Err(Error::Overflow));
+        assert_eq!(eval("-9223372036854775807 - 2"), Err(Error::Overflow));
+        assert_eq!(eval("4294967296 * 4294967296"), Err(Error::Overflow));
+        assert_eq!(eval("18446744073709551614 + 1"), Ok(to_value(18446744073709551615u64)));
+        assert_eq!(eval("9223372036854775807 + 0.5"), Ok(to_value(9223372036854775807.5)));
+
+        let expr = Expr::new("9223372036854775807 + 1").compile().unwrap();
+        assert_eq!(ExecOptions::new(&expr).overflow(Overflow::Saturating).exec(),
+                   Ok(to_value(i64::MAX)));
+        assert_eq!(ExecOptions::new(&expr).overflow(Overflow::Float).exec(),
+                   Ok(to_value(9223372036854775808.0)));
+        let expr = Expr::new("-4294967296 * 4294967296 * 2").compile().unwrap();
+        assert_eq!(ExecOptions::new(&expr).overflow(Overflow::Saturating).exec(),
+                   Ok(to_value(i64::MIN)));
+
+        assert_eq!(eval("2 ** 64"), Err(Error::Overflow));
+        assert_eq!(eval("(-2) ** 63"), Ok(to_value(i64::MIN)));
+        assert_eq!(eval("-(-9223372036854775807 - 1)"), Err(Error::Overflow));
+        assert_eq!(eval("jumlah(9223372036854775807, 1)"), Err(Error::Overflow));
+        assert_eq!(eval("kali_semua([4294967296, 4294967296])"), Err(Error::Overflow));
+        let saturating = |expression: &str| {
+            ExecOptions::new(&Expr::new(expression).compile().unwrap()).overflow(Overflow::Saturating).exec()
+        };
+        assert_eq!(saturating("2 ** 64"), Ok(to_value(i64::MAX)));
+        assert_eq!(saturating("(-2) ** 65"), Ok(to_value(i64::MIN)));
+        assert_eq!(saturating("-(-9223372036854775807 - 1)"), Ok(to_value(i64::MAX)));
+        assert_eq!(saturating("(-9223372036854775807 - 1) // -1"), Ok(to_value(i64::MAX)));
+        assert_eq!(saturating("jumlah([9223372036854775807, 1])"), Ok(to_value(i64::MAX)));
+        let expr = Expr::new("2 ** 64").compile().unwrap();
+        assert_eq!(ExecOptions::new(&expr).overflow(Overflow::Float).exec(),
+                   Ok(to_value(18446744073709551616.0)));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal_arithmetic() {
+        assert_eq!(eval("0.1 + 0.2 == 0.3"), Ok(to_value(true)));
+        assert_eq!(eval("0.3 - 0.1"), Ok(to_value(0.2)));
+        assert_eq!(eval("1.1 * 1.1"), Ok(to_value(1.21)));
+        assert_eq!(eval("0.3 / 0.1"), Ok(to_value(3.0)));
+        assert_eq!(eval("1e300 * 10.0"), Ok(to_value(1e301)));
+        assert_eq!(eval("0.1 + 0.2"), Ok(to_value(0.3)));
+        assert_eq!(eval("1 / 3"), Ok(to_value(1.0 / 3.0)));
+        assert_eq!(eval("2 / 3"), Ok(to_value(2.0 / 3.0)));
+        assert_eq!(eval("1.15 * 100 == 115 && 0.1 * 3 == 0.3"), Ok(to_value(true)));
+        // values are f64 between operations and `**` is not decimal
+        assert_eq!(eval("1.0 / 3 * 3"), Ok(to_value(0.9999999999999999)));
+        assert_eq!(eval("0.1 ** 2"), Ok(to_value(0.1f64.powf(2.0))));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint_arithmetic() {
+        let big = |digits: &str| Ok(to_value(digits));
+        assert_eq!(eval("9223372036854775807 + 1"), Ok(to_value(9223372036854775808u64)));
+        assert_eq!(eval("18446744073709551615 * 18446744073709551615"),
+                   big("340282366920938463426481119284349108225"));
+        assert_eq!(eval("123456789012345678901234567890 - 123456789012345678901234567889"),
+                   Ok(to_value(1)));
+        assert_eq!(eval("-123456789012345678901234567890 % 11"), Ok(to_value(-7)));
+        assert_eq!(eval("123456789012345678901234567890 > 123456789012345678901234567889"),
+                   Ok(to_value(true)));
+        assert_eq!(eval("mutlak(-99999999999999999999)"), big("99999999999999999999"));
+        assert_eq!(eval("-99999999999999999999 == -99999999999999999999"), Ok(to_value(true)));
+        assert_eq!(eval("str(99999999999999999999 + 1)"), Ok(to_value("100000000000000000000")));
+        assert_eq!(eval("99999999999999999999 + 0.5"), Ok(to_value(1e20)));
+        assert!(eval("99999999999999999999 + {}").is_err());
+        assert_eq!(eval("2 ** 100"), big("1267650600228229401496703205376"));
+        assert_eq!(eval("2 ** 4000000000"), Err(Error::TooLarge));
+        assert_eq!(eval("99999999999999999999 // 3"), big("33333333333333333333"));
+        assert_eq!(eval("int('99999999999999999999') + 1"), big("100000000000000000000"));
+        let mut object = HashMap::new();
+        object.insert("a", "18446744073709551616");
+        assert_eq!(eval("[99999999999999999999, {'a': 2 ** 64}]"),
+                   Ok(to_value(vec![to_value("99999999999999999999"), to_value(object)])));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint_builtins() {
+        assert_eq!(eval("tipe(99999999999999999999)"), Ok(to_value("angka")));
+        assert_eq!(eval("adalah_angka(99999999999999999999)"), Ok(to_value(true)));
+        assert_eq!(eval("adalah_objek(99999999999999999999)"), Ok(to_value(false)));
+        assert!(eval("panjang(99999999999999999999)").is_err());
+        assert_eq!(eval("kosong(99999999999999999999)"), Ok(to_value(false)));
+        assert_eq!(eval("kunci(99999999999999999999)"), Err(Error::ExpectedObject));
+        assert_eq!(eval("setel(99999999999999999999, 'a', 1)"), Err(Error::ExpectedObject));
+        assert_eq!(eval("ke_json([99999999999999999999])"), Ok(to_value("[\"99999999999999999999\"]")));
+        assert_eq!(eval("jumlah([99999999999999999999, 1])"), Ok(to_value("100000000000000000000")));
+        assert_eq!(eval("kali_semua(2 ** 40, 2 ** 40)"), Ok(to_value("1208925819614629174706176")));
+        assert_eq!(eval("99999999999999999999 & 1"), Ok(to_value(1)));
+        assert_eq!(eval("(2 ** 64) | 1"), Ok(to_value("18446744073709551617")));
+        assert_eq!(eval("(2 ** 64) ^ (2 ** 64)"), Ok(to_value(0)));
+        assert_eq!(eval("(2 ** 64) << 2"), Ok(to_value("73786976294838206464")));
+        assert_eq!(eval("(2 ** 70) >> 10"), Ok(to_value(1u64 << 60)));
+        assert_eq!(eval("lantai(99999999999999999999)"), Ok(to_value("99999999999999999999")));
+        assert_eq!(eval("langit(99999999999999999999)"), Ok(to_value("99999999999999999999")));
+        assert_eq!(eval("bulatkan(99999999999999999999)"), Ok(to_value("99999999999999999999")));
+        assert_eq!(eval("isi_kiri(2 ** 64, 22, '0')"), Ok(to_value("0018446744073709551616")));
+        assert_eq!(eval("format('%d', 99999999999999999999)"), Ok(to_value("99999999999999999999")));
+        assert_eq!(eval("gabung_dalam({'a': 99999999999999999999}, {'a': {'b': 1}}) == {'a': {'b': 1}}"),
+                   Ok(to_value(true)));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint_not_forgeable() {
+        let mut forged = HashMap::new();
+        forged.insert("$bigint", "5");
+        assert!(Expr::new("forged + 1").value("forged", &forged).exec().is_err());
+        assert_eq!(Expr::new("tipe(forged)").value("forged", &forged).exec(), Ok(to_value("objek")));
+        assert_eq!(
+            Expr::new("host(99999999999999999999)")
+                .function("host", |values| Ok(to_value(values[0].is_string())))
+                .exec(),
+            Ok(to_value(true))
+        );
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(eval("ke_angka('42') == 42"), Ok(to_value(true)));
+        assert_eq!(eval("ke_angka(' 2.5 ')"), Ok(to_value(2.5)));
+        assert_eq!(eval("ke_angka(7)"), Ok(to_value(7)));
+        assert_eq!(eval("ke_bulat('12.7')"), Ok(to_value(12)));
+        assert_eq!(eval("ke_pecahan('2,75', ',')"), Ok(to_value(2.75)));
+        assert_eq!(eval("ke_angka('1.234.567,5', ',')"), Ok(to_value(1234567.5)));
+        assert_eq!(eval("ke_bulat('1,234', '.')"), Ok(to_value(1234)));
+        assert_eq!(eval("ke_angka('abc')"),
+                   Err(Error::CanNotCast(to_value("abc"), "float".to_owned())));
+        assert_eq!(eval("ke_pecahan('x,5', ',')"),
+                   Err(Error::CanNotCast(to_value("x,5"), "float".to_owned())));
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(eval("format_angka(1234567.891, 2)"), Ok(to_value("1.234.567,89")));
+        assert_eq!(eval("format_angka(1234567)"), Ok(to_value("1.234.567")));
+        assert_eq!(eval("format_angka(999.5)"), Ok(to_value("1.000")));
+        assert_eq!(eval("format_angka(1234.5)"), Ok(to_value("1.235")));
+        assert_eq!(eval("format_angka(0.125, 2)"), Ok(to_value("0,13")));
+        assert_eq!(eval("format_angka(-1234.5, 1)"), Ok(to_value("-1.234,5")));
+        assert_eq!(eval("format_angka(-0.001, 2)"), Ok(to_value("0,00")));
+        assert_eq!(eval("format_angka(1234567.891, 2, ',', '.')"), Ok(to_value("1,234,567.89")));
+        assert_eq!(eval("format_angka(1234567, 0, ' ')"), Ok(to_value("1 234 567")));
+        assert_eq!(eval("format_angka('12')"), Err(Error::ExpectedNumber));
+    }
+
+    #[test]
+    fn test_rupiah() {
+        assert_eq!(eval("rupiah(1500000)"), Ok(to_value("Rp1.500.000")));
+        assert_eq!(eval("rupiah(1500.5, 2)"), Ok(to_value("Rp1.500,50")));
+        assert_eq!(eval("rupiah(-25000, 0, benar)"), Ok(to_value("-Rp 25.000")));
+        assert_eq!(eval("rupiah(0)"), Ok(to_value("Rp0")));
+        assert_eq!(eval("rupiah(1, 0, 1)"), Err(Error::ExpectedBoolean(to_value(1))));
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        assert_eq!(eval("ke_romawi(2024)"), Ok(to_value("MMXXIV")));
+        assert_eq!(eval("ke_romawi(10)"), Ok(to_value("X")));
+        assert_eq!(eval("dari_romawi('MMXXIV')"), Ok(to_value(2024)));
+        assert_eq!(eval("dari_romawi('mcmxcix')"), Ok(to_value(1999)));
+        assert_eq!(eval("ke_romawi(0)"), Err(Error::CanNotCast(to_value(0), "romawi".to_owned())));
+        assert_eq!(eval("dari_romawi('IIII')"),
+                   Err(Error::CanNotCast(to_value("IIII"), "int".to_owned())));
+        assert_eq!(eval("dari_romawi('AB')"), Err(Error::CanNotCast(to_value("AB"), "int".to_owned())));
+    }
+
+    #[test]
+    fn test_sort() {
+        assert_eq!(eval("urutkan(untaian(3, 1, 2))"), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("urutkan(untaian('b', 'c', 'a'), 'desc')"), Ok(to_value(vec!["c", "b", "a"])));
+        assert!(matches!(eval("urutkan(untaian(1, 'a'))"), Err(Error::UnsupportedTypes(_, _))));
+        assert_eq!(eval("urutkan(untaian(1), 'naik')"),
+                   Err(Error::Custom("Unknown sort direction: naik".to_owned())));
+        assert_eq!(eval("urutkan(1)"), Err(Error::ExpectedArray));
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let item = |nama: &str, harga: Option<i64>| {
+            let mut item = HashMap::new();
+            item.insert("nama", to_value(nama));
+            if let Some(harga) = harga {
+                item.insert("harga", to_value(harga));
+            }
+            item
+        };
+        let items = vec![item("b", Some(300)), item("a", Some(100)), item("c", None)];
+        let names = |expr: &str| {
+            let sorted = Expr::new(expr).value("items", &items).exec().unwrap();
+            sorted.as_array().unwrap().iter().map(|item| item["nama"].clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(names("urutkan_oleh(items, 'nama')"), vec!["a", "b", "c"]);
+        assert_eq!(names("urutkan_oleh(items, 'harga')"), vec!["c", "a", "b"]);
+        assert_eq!(names("urutkan_oleh(items, 'harga', 'desc')"), vec!["b", "a", "c"]);
+        assert_eq!(eval("urutkan_oleh(untaian(1), 'nama')"), Err(Error::ExpectedObject));
+    }
+
+    #[test]
+    fn test_reverse_first_last() {
+        assert_eq!(eval("balik(untaian(1, 2, 3))"), Ok(to_value(vec![3, 2, 1])));
+        assert_eq!(eval("pertama(untaian('a', 'b'))"), Ok(to_value("a")));
+        assert_eq!(eval("terakhir(untaian('a', 'b'))"), Ok(to_value("b")));
+        assert_eq!(eval("pertama(untaian())"), Ok(Value::Null));
+        assert_eq!(eval("terakhir(untaian())"), Ok(Value::Null));
+        assert_eq!(eval("balik('abc')"), Err(Error::ExpectedArray));
+    }
+
+    #[test]
+    fn test_unique() {
+        assert_eq!(eval("unik(untaian(1, 2, 1.0, 3, 2))"), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("unik(untaian(untaian(1), untaian(1), 'a'))"),
+                   Ok(to_value(vec![to_value(vec![1]), to_value("a")])));
+        assert_eq!(eval("panjang(unik(untaian()))"), Ok(to_value(0)));
+
+        let item = |id: i64, kota: &str| {
+            let mut item = HashMap::new();
+            item.insert("id", to_value(id));
+            item.insert("kota", to_value(kota));
+            item
+        };
+        let items = vec![item(1, "Bandung"), item(2, "Jakarta"), item(3, "Bandung")];
+        assert_eq!(Expr::new("unik_oleh(items, 'kota')").value("items", &items).exec(),
+                   Ok(to_value(vec![item(1, "Bandung"), item(2, "Jakarta")])));
+        assert_eq!(eval("unik_oleh(untaian(1), 'kota')"), Err(Error::ExpectedObject));
+    }
+
+    #[test]
+    fn test_flatten_and_chunk() {
+        assert_eq!(eval("ratakan(untaian(1, untaian(2, untaian(3)), 4))"),
+                   Ok(to_value(vec![to_value(1), to_value(2), to_value(vec![3]), to_value(4)])));
+        assert_eq!(eval("ratakan(untaian(1, untaian(2, untaian(3))), 5)"), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("ratakan(untaian(untaian(1)), 0)"), Ok(to_value(vec![vec![1]])));
+        assert_eq!(eval("potong_kelompok(untaian(1, 2, 3, 4, 5), 2)"),
+                   Ok(to_value(vec![vec![1, 2], vec![3, 4], vec![5]])));
+        assert_eq!(eval("panjang(potong_kelompok(untaian(), 3))"), Ok(to_value(0)));
+        assert_eq!(eval("potong_kelompok(untaian(1), 0)"),
+                   Err(Error::Custom("Invalid chunk size: 0".to_owned())));
+    }
+
+    #[test]
+    fn test_zip_and_enumerate() {
+        assert_eq!(eval("zip(untaian(1, 2, 3), untaian('a', 'b'))"),
+                   Ok(to_value(vec![vec![to_value(1), to_value("a")], vec![to_value(2), to_value("b")]])));
+        assert_eq!(eval("enumerasi(untaian('x', 'y'))"),
+                   Ok(to_value(vec![vec![to_value(0), to_value("x")], vec![to_value(1), to_value("y")]])));
+        assert_eq!(eval("panjang(enumerasi(untaian()))"), Ok(to_value(0)));
+        assert_eq!(eval("zip(untaian(1), 2)"), Err(Error::ExpectedArray));
+    }
+
+    #[test]
+    fn test_array_building() {
+        assert_eq!(eval("sambung(untaian(1), untaian(2, 3), untaian())"), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("tambah(untaian(1, 2), 3)"), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("tambah(untaian(1), untaian(2))"),
+                   Ok(to_value(vec![to_value(1), to_value(vec![2])])));
+        assert_eq!(eval("sisip(untaian(1, 3), 1, 2)"), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("sisip(untaian(1, 2), -1, 9)"), Ok(to_value(vec![1, 9, 2])));
+        assert_eq!(eval("sisip(untaian(1), 10, 2)"), Ok(to_value(vec![1, 2])));
+        assert_eq!(eval("hapus_indeks(untaian(1, 2, 3), 0)"), Ok(to_value(vec![2, 3])));
+        assert_eq!(eval("hapus_indeks(untaian(1, 2, 3), -1)"), Ok(to_value(vec![1, 2])));
+        assert_eq!(eval("hapus_indeks(untaian(1, 2, 3), 5)"), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("sambung(untaian(1), 2)"), Err(Error::ExpectedArray));
+    }
+
+    #[test]
+    fn test_take_drop_slice() {
+        assert_eq!(eval("ambil(untaian(1, 2, 3), 2)"), Ok(to_value(vec![1, 2])));
+        assert_eq!(eval("ambil(untaian(1, 2, 3), -1)"), Ok(to_value(vec![1, 2])));
+        assert_eq!(eval("ambil(untaian(1, 2, 3), 10)"), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("lewati(untaian(1, 2, 3), 1)"), Ok(to_value(vec![2, 3])));
+        assert_eq!(eval("lewati(untaian(1, 2, 3), -1)"), Ok(to_value(vec![3])));
+        assert_eq!(eval("iris(untaian(1, 2, 3, 4), 1, 3)"), Ok(to_value(vec![2, 3])));
+        assert_eq!(eval("iris(untaian(1, 2, 3, 4), -2)"), Ok(to_value(vec![3, 4])));
+        assert_eq!(eval("iris(untaian(1, 2, 3), 2, 1)"), Ok(to_value(Vec::<i64>::new())));
+        assert_eq!(eval("ambil(5, 1)"), Err(Error::ExpectedArray));
+    }
+
+    #[test]
+    fn test_count_any_all() {
+        assert_eq!(eval("hitung_jika(untaian(5, 12, 20), x => x > 10)"), Ok(to_value(2)));
+        assert_eq!(eval("ada(untaian(1, 2, 3), n => n % 2 == 0)"), Ok(to_value(true)));
+        assert_eq!(eval("semua(untaian(1, 2, 3), n => n > 1)"), Ok(to_value(false)));
+        assert_eq!(eval("semua(untaian(), n => n > 1) && !ada(untaian(), n => benar)"),
+                   Ok(to_value(true)));
+        assert_eq!(eval("hitung_jika(untaian('a', 'bb'), (s) => panjang(s) == min(2, 5))"),
+                   Ok(to_value(1)));
+        assert_eq!(eval("ada(untaian(1), x => x)"), Err(Error::ExpectedBoolean(to_value(1))));
+        assert_eq!(eval("ada(x => benar)"), Err(Error::ArgumentsLess(2)));
+
+        let mut order = HashMap::new();
+        order.insert("total", 150000);
+        let orders = vec![order.clone(), order];
+        assert_eq!(Expr::new("semua(orders, o => o.total > batas)")
+                       .value("orders", orders)
+                       .value("batas", 100000)
+                       .exec(),
+                   Ok(to_value(true)));
+    }
+
+    #[test]
+    fn test_map() {
+        assert_eq!(eval("peta(untaian(1, 2, 3), x => x * 2)"), Ok(to_value(vec![2, 4, 6])));
+        assert_eq!(eval("peta(untaian(), x => x * 2)"), Ok(to_value(Vec::<i64>::new())));
+        assert_eq!(eval("jumlah(peta(untaian('ab', 'c'), s => panjang(s)))"), Ok(to_value(3)));
+        assert_eq!(eval("peta([1, 2], x => x, 3)"), Err(Error::ArgumentsGreater(2)));
+        assert_eq!(eval("peta([1, 2])"), Err(Error::ArgumentsLess(2)));
+        assert_eq!(eval("peta([1, 2], 3)"), Err(Error::ExpectedFunction));
+        assert_eq!(eval("lipat([1, 2], (acc, x) => acc + x)"), Err(Error::ArgumentsLess(3)));
+
+        let item = |harga: i64, qty: i64| {
+            let mut item = HashMap::new();
+            item.insert("harga", harga);
+            item.insert("qty", qty);
+            item
+        };
+        assert_eq!(Expr::new("peta(items, x => x.harga * x.qty)")
+                       .value("items", vec![item(1000, 2), item(500, 3)])
+                       .value("x", 7)
+                       .exec(),
+                   Ok(to_value(vec![2000, 1500])));
+        assert_eq!(eval("peta('abc', x => x)"), Err(Error::ExpectedArray));
+        assert_eq!(Expr::new("peta([1, 2], x => peta([10, 20], y => x * y + k))").value("k", 1).exec(),
+                   Ok(to_value(vec![vec![11, 21], vec![21, 41]])));
+        assert_eq!(eval("peta([1, 2], x => peta([3], x => x)) == [[3], [3]]"), Ok(to_value(true)));
+    }
+
+    #[test]
+    fn test_filter() {
+        assert_eq!(eval("saring(untaian(1, 2, 3, 4), n => n % 2 == 0)"), Ok(to_value(vec![2, 4])));
+        assert_eq!(eval("saring(untaian(1), n => n > 5)"), Ok(to_value(Vec::<i64>::new())));
+
+        let order = |status: &str, total: i64| {
+            let mut order = HashMap::new();
+            order.insert("status", to_value(status));
+            order.insert("total", to_value(total));
+            order
+        };
+        let orders = vec![order("paid", 250000), order("paid", 50000), order("new", 300000)];
+        let expr = "saring(orders, o => o.status == 'paid' && o.total > 100000)";
+        assert_eq!(Expr::new(expr).value("orders", &orders).exec(),
+                   Ok(to_value(vec![order("paid", 250000)])));
+        assert_eq!(Expr::new("panjang(saring(orders, o => o.status == 'paid'))")
+                       .value("orders", &orders)
+                       .exec(),
+                   Ok(to_value(2)));
+    }
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(eval("lipat(untaian(1, 2, 3), 0, (acc, x) => acc + x)"), Ok(to_value(6)));
+        assert_eq!(eval("lipat(untaian('a', 'b'), '', (acc, s) => s + acc)"), Ok(to_value("ba")));
+        assert_eq!(eval("lipat(untaian(), 42, (acc, x) => acc + x)"), Ok(to_value(42)));
+        assert_eq!(eval("lipat(untaian(1), (acc, x) => acc)"), Err(Error::ArgumentsLess(3)));
+
+        let nilai = |nilai: i64, bobot: i64| {
+            let mut item = HashMap::new();
+            item.insert("nilai", nilai);
+            item.insert("bobot", bobot);
+            item
+        };
+        let expr = "lipat(items, 0, (acc, x) => acc + x.nilai * x.bobot) / jumlah(peta(items, x => x.bobot))";
+        assert_eq!(Expr::new(expr).value("items", vec![nilai(80, 1), nilai(90, 3)]).exec(),
+                   Ok(to_value(87.5)));
+    }
+
+    #[test]
+    fn test_group_by() {
+        let item = |nama: &'static str, kategori: &'static str| {
+            let mut item = HashMap::new();
+            item.insert("nama", nama);
+            item.insert("kategori", kategori);
+            item
+        };
+        let items = vec![item("apel", "buah"), item("bayam", "sayur"), item("jeruk", "buah")];
+        let mut groups = HashMap::new();
+        groups.insert("buah", vec![item("apel", "buah"), item("jeruk", "buah")]);
+        groups.insert("sayur", vec![item("bayam", "sayur")]);
+        assert_eq!(Expr::new("kelompokkan(items, 'kategori')").value("items", &items).exec(),
+                   Ok(to_value(&groups)));
+        assert_eq!(Expr::new("kelompokkan(items, x => x.kategori)").value("items", &items).exec(),
+                   Ok(to_value(&groups)));
+
+        let mut parity = HashMap::new();
+        parity.insert("false", vec![1, 3]);
+        parity.insert("true", vec![2]);
+        assert_eq!(eval("kelompokkan(untaian(1, 2, 3), n => n % 2 == 0)"), Ok(to_value(parity)));
+        assert_eq!(eval("kelompokkan(untaian(1), 'kategori')"), Err(Error::ExpectedObject));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(eval("cari(untaian(1, 5, 8), n => n > 3)"), Ok(to_value(5)));
+        assert_eq!(eval("cari(untaian(1, 2), n => n > 3)"), Ok(Value::Null));
+        assert_eq!(eval("cari_indeks(untaian('a', 'b'), s => s == 'b')"), Ok(to_value(1)));
+        assert_eq!(eval("cari_indeks(untaian('a'), s => s == 'z')"), Ok(to_value(-1)));
+        // `'a' > 0` would fail, but the lambda is not called past the first match
+        assert_eq!(eval("cari(untaian(1, 'a'), n => n > 0)"), Ok(to_value(1)));
+        assert!(eval("cari(untaian('a', 1), n => n > 0)").is_err());
+    }
+
+    #[test]
+    fn test_min_max_by() {
+        let product = |nama: &str, harga: i64, skor: f64| {
+            let mut product = HashMap::new();
+            product.insert("nama", to_value(nama));
+            product.insert("harga", to_value(harga));
+            product.insert("skor", to_value(skor));
+            product
+        };
+        let products = vec![product("a", 300, 4.5), product("b", 100, 3.0), product("c", 100, 4.9)];
+        assert_eq!(Expr::new("min_oleh(items, 'harga')").value("items", &products).exec(),
+                   Ok(to_value(product("b", 100, 3.0))));
+        assert_eq!(Expr::new("maks_oleh(items, i => i.skor)").value("items", &products).exec(),
+                   Ok(to_value(product("c", 100, 4.9))));
+        assert_eq!(Expr::new("maks_oleh(items, 'harga').nama").value("items", &products).exec(),
+                   Ok(to_value("a")));
+        assert_eq!(eval("min_oleh(untaian('abc', 'x', 'yz'), s => panjang(s))"), Ok(to_value("x")));
+        assert_eq!(eval("maks_oleh(untaian(), 'harga')"), Ok(Value::Null));
+        assert_eq!(eval("min_oleh(untaian(1), 'harga')"), Err(Error::ExpectedObject));
+    }
+
+    #[test]
+    fn test_lambda_errors() {
+        assert_eq!(eval("ada(untaian(1), 1 => benar)"), Err(Error::InvalidLambda));
+        assert_eq!(eval("ada(untaian(1), (a,) => benar)"), Err(Error::InvalidLambda));
+        assert_eq!(eval("panjang(x => 1)"), Err(Error::FunctionNotExists("panjang".to_owned())));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        assert_eq!(eval("irisan(untaian(1, 2, 2, 3), untaian(2, 3.0, 4))"), Ok(to_value(vec![2, 3])));
+        assert_eq!(eval("gabungan_set(untaian(1, 2), untaian(2, 3))"), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("selisih(untaian('admin', 'staf', 'tamu'), untaian('tamu'))"),
+                   Ok(to_value(vec!["admin", "staf"])));
+        assert_eq!(eval("memuat_semua(untaian('baca', 'tulis'), untaian('tulis'))"), Ok(to_value(true)));
+        assert_eq!(eval("memuat_semua(untaian('baca'), untaian('baca', 'hapus'))"), Ok(to_value(false)));
+        assert_eq!(eval("memuat_semua(untaian(), untaian())"), Ok(to_value(true)));
+        assert_eq!(eval("irisan(untaian(untaian(1)), untaian(untaian(1.0)))"), Ok(to_value(vec![vec![1]])));
+        assert_eq!(eval("selisih(untaian(1), 1)"), Err(Error::ExpectedArray));
+    }
+
+    #[test]
+    fn test_range_function() {
+        assert_eq!(eval("rentang(4)"), Ok(to_value(vec![0, 1, 2, 3])));
+        assert_eq!(eval("rentang(2, 5)"), Ok(to_value(vec![2, 3, 4])));
+        assert_eq!(eval("rentang(0, 10, 3)"), Ok(to_value(vec![0, 3, 6, 9])));
+        assert_eq!(eval("rentang(5, 0, -2)"), Ok(to_value(vec![5, 3, 1])));
+        assert_eq!(eval("rentang(3, 1)"), Ok(to_value(Vec::<i64>::new())));
+        assert_eq!(Expr::new("rentang(a, b)").value("a", 1).value("b", 3).exec(), Ok(to_value(vec![1, 2])));
+        assert_eq!(eval("rentang(0, 5, 0)"), Err(Error::InvalidRange("rentang(0, 5, 0)".to_owned())));
+        assert_eq!(eval("rentang(1.5)"), Err(Error::ExpectedNumber));
+        assert_eq!(eval("rentang(0, 9000000000000000000)"), Err(Error::TooLarge));
+        assert_eq!(eval("rentang(9000000000000000000, 0, -1)"), Err(Error::TooLarge));
+        assert_eq!(eval("panjang(rentang(-9000000000000000000, 9000000000000000000, 1000000000000000000))"), Ok(to_value(18)));
+    }
+
+    #[test]
+    fn test_object_entries() {
+        let mut harga = HashMap::new();
+        harga.insert("teh", 5000);
+        harga.insert("kopi", 8000);
+        let expr = |source: &str| Expr::new(source).value("harga", &harga).exec();
+        assert_eq!(expr("kunci(harga)"), Ok(to_value(vec!["kopi", "teh"])));
+        assert_eq!(expr("nilai(harga)"), Ok(to_value(vec![8000, 5000])));
+        assert_eq!(expr("entri(harga)"), Ok(to_value(vec![("kopi", 8000), ("teh", 5000)])));
+        assert_eq!(expr("jumlah(nilai(harga))"), Ok(to_value(13000)));
+        assert_eq!(eval("kunci(untaian(1, 2))"), Err(Error::ExpectedObject));
+    }
+
+    #[test]
+    fn test_has_and_get_with_default() {
+        let mut user = HashMap::new();
+        user.insert("nama", to_value("budi"));
+        user.insert("email", Value::Null);
+        let expr = |source: &str| Expr::new(source).value("user", &user).exec();
+        assert_eq!(expr("punya(user, 'nama')"), Ok(to_value(true)));
+        assert_eq!(expr("punya(user, 'email')"), Ok(to_value(true)));
+        assert_eq!(expr("punya(user, 'umur')"), Ok(to_value(false)));
+        assert_eq!(expr("ambil_nilai(user, 'nama', 'anon')"), Ok(to_value("budi")));
+        assert_eq!(expr("ambil_nilai(user, 'email', 'kosong')"), Ok(Value::Null));
+        assert_eq!(expr("ambil_nilai(user, 'umur', 17)"), Ok(to_value(17)));
+        assert_eq!(eval("punya('budi', 'nama')"), Err(Error::ExpectedObject));
+    }
+
+    #[test]
+    fn test_path_function() {
+        let config: Value = serde_json::from_str(r#"{"a": {"b": [1, 2, {"c": "dalam"}]}}"#).unwrap();
+        let expr = |path: &str| Expr::new("jalur(config, path)").value("config", &config).value("path", path).exec();
+        assert_eq!(expr("a.b[2].c"), Ok(to_value("dalam")));
+        assert_eq!(expr("a.b.1"), Ok(to_value(2)));
+        assert_eq!(expr("a.b[9].c"), Ok(Value::Null));
+        assert_eq!(expr("a.tidak.ada"), Ok(Value::Null));
+        assert_eq!(expr(""), Ok(config.clone()));
+        assert_eq!(expr("a..b"), Err(Error::InvalidPath("a..b".to_owned())));
+        assert_eq!(expr("a.b[x]"), Err(Error::InvalidPath("a.b[x]".to_owned())));
+    }
+
+    #[test]
+    fn test_pick_and_omit() {
+        let user: Value = serde_json::from_str(r#"{"nama": "budi", "email": "b@x.id", "password": "rahasia"}"#).unwrap();
+        let expr = |source: &str| Expr::new(source).value("user", &user).exec();
+        let picked: Value = serde_json::from_str(r#"{"nama": "budi", "email": "b@x.id"}"#).unwrap();
+        assert_eq!(expr("pilih(user, 'nama', 'email', 'umur')"), Ok(picked.clone()));
+        assert_eq!(expr("buang(user, 'password')"), Ok(picked.clone()));
+        assert_eq!(expr("buang(user, untaian('password'))"), Ok(picked));
+        assert_eq!(expr("pilih(user, 1)"), Err(Error::ExpectedString));
+    }
+
+    #[test]
+    fn test_deep_merge() {
+        let json = |source: &str| serde_json::from_str::<Value>(source).unwrap();
+        let base = json(r#"{"db": {"host": "localhost", "port": 5432}, "tags": ["a"]}"#);
+        let local = json(r#"{"db": {"port": 6543, "user": "admin"}, "tags": ["b"]}"#);
+        let expr = |source: &str| Expr::new(source).value("base", &base).value("local", &local).exec();
+        assert_eq!(expr("gabung_dalam(base, local)"),
+                   Ok(json(r#"{"db": {"host": "localhost", "port": 6543, "user": "admin"}, "tags": ["b"]}"#)));
+        assert_eq!(expr("gabung_dalam(base, local, 'sambung')"),
+                   Ok(json(r#"{"db": {"host": "localhost", "port": 6543, "user": "admin"}, "tags": ["a", "b"]}"#)));
+        assert_eq!(expr("gabung_dalam(base, local, 'campur')"),
+                   Err(Error::Custom("Unknown merge mode: campur".to_owned())));
+        assert_eq!(expr("gabung_dalam(base, 1)"), Err(Error::ExpectedObject));
+    }
+
+    #[test]
+    fn test_object_literal() {
+        let json = |source: &str| serde_json::from_str::<Value>(source).unwrap();
+        let mut user = HashMap::new();
+        user.insert("nama", "budi");
+        assert_eq!(Expr::new("{ nama: user.nama, total: qty * harga, }")
+                       .value("user", &user)
+                       .value("qty", 3)
+                       .value("harga", 2500)
+                       .exec(),
+                   Ok(json(r#"{"nama": "budi", "total": 7500}"#)));
+        assert_eq!(eval("{ 'kode pos': '12345', alamat: { kota: 'Bandung', tags: untaian(1, 2) } }"),
+                   Ok(json(r#"{"kode pos": "12345", "alamat": {"kota": "Bandung", "tags": [1, 2]}}"#)));
+        assert_eq!(eval("{ a: 1 < 2 ? 'ya' : 'tidak' }.a"), Ok(to_value("ya")));
+        assert_eq!(eval("{}"), Ok(json("{}")));
+        assert_eq!(eval("{ a: 1"), Err(Error::UnpairedBrackets));
+        assert_eq!(eval("{ a b: 1 }"), Err(Error::InvalidObjectLiteral("a b: 1".to_owned())));
+    }
+
+    #[test]
+    fn test_array_literal() {
+        assert_eq!(Expr::new("[1, 2, x + 1]").value("x", 2).exec(), Ok(to_value(vec![1, 2, 3])));
+        assert_eq!(eval("[[1, 2], [3], [],]"), Ok(to_value(vec![vec![1, 2], vec![3], vec![]])));
+        assert_eq!(eval("[10, 20, 30][1]"), Ok(to_value(20)));
+        assert_eq!(eval("2 in [1, 2]"), Ok(to_value(true)));
+        assert_eq!(eval("jumlah([1, ...[2, 3]])"), Ok(to_value(6)));
+        assert_eq!(eval("peta([1, 2], x => [x, x * 10])"), Ok(to_value(vec![vec![1, 10], vec![2, 20]])));
+        assert_eq!(eval("{ a: [1, { b: 2 }] }.a[1].b"), Ok(to_value(2)));
+        assert_eq!(eval("[1, 2"), Err(Error::UnpairedBrackets));
+    }
+
+    #[test]
+    fn test_set_field_and_path() {
+        let json = |source: &str| serde_json::from_str::<Value>(source).unwrap();
+        let user = json(r#"{"nama": "budi", "alamat": {"kota": "Bandung"}, "tags": [{"id": 1}]}"#);
+        let expr = |source: &str| Expr::new(source).value("user", &user).exec();
+        assert_eq!(expr("setel(user, 'nama', 'ani').nama"), Ok(to_value("ani")));
+        assert_eq!(expr("setel(user, 'umur', 20).umur"), Ok(to_value(20)));
+        assert_eq!(expr("setel_jalur(user, 'alamat.kota', 'Bogor').alamat"), Ok(json(r#"{"kota": "Bogor"}"#)));
+        assert_eq!(expr("setel_jalur(user, 'alamat.geo.lat', 6).alamat.geo"), Ok(json(r#"{"lat": 6}"#)));
+        assert_eq!(expr("setel_jalur(user, 'tags[0].id', 2).tags"), Ok(json(r#"[{"id": 2}]"#)));
+        assert_eq!(expr("user.nama"), Ok(to_value("budi")));
+        assert_eq!(expr("setel_jalur(user, 'tags[3].id', 2)"), Err(Error::InvalidPath("tags[3].id".to_owned())));
+        assert_eq!(expr("setel_jalur(user, 'nama.depan', 'x')"), Err(Error::ExpectedObject));
+    }
+
+    #[test]
+    fn test_from_entries() {
+        let json = |source: &str| serde_json::from_str::<Value>(source).unwrap();
+        let user = json(r#"{"nama": "budi", "umur": 20, "kota": "Bandung"}"#);
+        let expr = |source: &str| Expr::new(source).value("user", &user).exec();
+        assert_eq!(eval("dari_entri([['a', 1], ['b', 2]])"), Ok(json(r#"{"a": 1, "b": 2}"#)));
+        assert_eq!(expr("dari_entri(entri(user))"), Ok(user.clone()));
+        assert_eq!(expr("dari_entri(saring(entri(user), e => e[1] != 20))"),
+                   Ok(json(r#"{"nama": "budi", "kota": "Bandung"}"#)));
+        assert_eq!(expr("dari_entri(peta(entri(user), e => [huruf_besar(e[0]), e[1]]))"),
+                   Ok(json(r#"{"NAMA": "budi", "UMUR": 20, "KOTA": "Bandung"}"#)));
+        assert_eq!(eval("dari_entri([['a', 1, 2]])"), Err(Error::ExpectedArray));
+    }
+
+    #[test]
+    fn test_type_of() {
+        assert_eq!(eval("tipe(1.5)"), Ok(to_value("angka")));
+        assert_eq!(eval("tipe('a')"), Ok(to_value("teks")));
+        assert_eq!(eval("tipe([1])"), Ok(to_value("larik")));
+        assert_eq!(eval("tipe({ a: 1 })"), Ok(to_value("objek")));
+        assert_eq!(eval("tipe(benar)"), Ok(to_value("boolean")));
+        assert_eq!(eval("tipe(tidak_ada)"), Ok(to_value("nihil")));
+        assert_eq!(Expr::new("tipe(x) == 'teks' ? huruf_besar(x) : x").value("x", "ya").exec(), Ok(to_value("YA")));
+    }
+
+    #[test]
+    fn test_type_predicates() {
+        assert_eq!(eval("adalah_angka(-2)"), Ok(to_value(true)));
+        assert_eq!(eval("adalah_angka('2')"), Ok(to_value(false)));
+        assert_eq!(eval("adalah_teks('2')"), Ok(to_value(true)));
+        assert_eq!(eval("adalah_larik([])"), Ok(to_value(true)));
+        assert_eq!(eval("adalah_objek([])"), Ok(to_value(false)));
+        assert_eq!(eval("adalah_bool(salah)"), Ok(to_value(true)));
+        assert_eq!(eval("adalah_nihil(x)"), Ok(to_value(true)));
+        assert_eq!(Expr::new("adalah_larik(x) ? panjang(x) : 0").value("x", 5).exec(), Ok(to_value(0)));
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(eval("ke_teks('a')"), Ok(to_value("a")));
+        assert_eq!(eval("ke_teks(nihil)"), Ok(to_value("null")));
+        assert_eq!(eval("ke_teks(benar) + '/' + ke_teks(salah)"), Ok(to_value("true/false")));
+        assert_eq!(eval("ke_teks(42)"), Ok(to_value("42")));
+        assert_eq!(eval("ke_teks(2.0)"), Ok(to_value("2")));
+        assert_eq!(eval("ke_teks(2.5)"), Ok(to_value("2.5")));
+        assert_eq!(eval("ke_teks(1e21)"), Ok(to_value("1000000000000000000000")));
+        assert_eq!(eval("ke_teks([1, 'a', nihil])"), Ok(to_value(r#"[1,"a",null]"#)));
+        assert_eq!(eval("ke_teks({ b: 1, a: benar })"), Ok(to_value(r#"{"a":true,"b":1}"#)));
+        for value in &["benar", "2.5", "1e21", "[1, benar]"] {
+            let expected = eval(&format!("ke_teks({})", value));
+            assert_eq!(eval(&format!("str({})", value)), expected, "{}", value);
+            assert_eq!(eval(&format!("\"${{{}}}\"", value)), expected, "{}", value);
+            assert_eq!(eval(&format!("gabung([{}])", value)), expected, "{}", value);
+            assert_eq!(eval(&format!("format('%s', {})", value)), expected, "{}", value);
+        }
+        assert_eq!(eval("gabung([nihil, benar], '/')"), Ok(to_value("null/true")));
+    }
+
+    #[test]
+    fn test_to_bool() {
+        for falsy in &["nihil", "salah", "0", "0.0", "''", "[]", "{}"] {
+            assert_eq!(eval(&format!("ke_bool({})", falsy)), Ok(to_value(false)), "{}", falsy);
+        }
+        for truthy in &["benar", "-1", "0.5", "'0'", "[0]", "{ a: nihil }"] {
+            assert_eq!(eval(&format!("ke_bool({})", truthy)), Ok(to_value(true)), "{}", truthy);
+        }
+    }
+
+    #[test]
+    fn test_truthiness_option() {
+        let mut context = Context::new();
+        context.insert("nama".to_owned(), to_value("budi"));
+        context.insert("umur".to_owned(), to_value(0));
+        let contexts = vec![context];
+        let expr = Expr::new("nama && umur").value("nama", "budi").value("umur", 0);
+        assert!(matches!(expr.exec(), Err(Error::UnsupportedTypes(..))));
+        assert_eq!(ExecOptions::new(&expr).contexts(&contexts).truthiness(true).exec(), Ok(to_value(false)));
+        let expr = Expr::new("nama || umur");
+        assert_eq!(ExecOptions::new(&expr).contexts(&contexts).truthiness(true).exec(), Ok(to_value(true)));
+        let expr = Expr::new("'' || []");
+        assert_eq!(ExecOptions::new(&expr).truthiness(true).exec(), Ok(to_value(false)));
+    }
+
+    #[test]
+    fn test_default_function() {
+        assert_eq!(eval("bawaan(nama, 'anon')"), Ok(to_value("anon")));
+        assert_eq!(eval("bawaan('', 'anon')"), Ok(to_value("anon")));
+        assert_eq!(eval("bawaan([], [0])"), Ok(to_value(vec![0])));
+        assert_eq!(eval("bawaan(0, 5)"), Ok(to_value(0)));
+        assert_eq!(eval("bawaan(salah, benar)"), Ok(to_value(false)));
+        assert_eq!(Expr::new("bawaan(nama, 'anon')").value("nama", "budi").exec(), Ok(to_value("budi")));
+        assert_eq!(eval("bawaan('ada', tidak_ada())"), Ok(to_value("ada")));
+        assert_eq!(eval("bawaan('', tidak_ada())"), Err(Error::FunctionNotExists("tidak_ada".to_owned())));
+        assert_eq!(eval("bawaan(1)"), Err(Error::ArgumentsLess(2)));
+    }
+
+    #[test]
+    fn test_local_number() {
+        assert_eq!(eval("angka_lokal('1.234,56')"), Ok(to_value(1234.56)));
+        assert_eq!(eval("angka_lokal('1.234.567')"), Ok(to_value(1234567.0)));
+        assert_eq!(eval("angka_lokal(' -0,5 ')"), Ok(to_value(-0.5)));
+        assert_eq!(eval("angka_lokal(7)"), Ok(to_value(7.0)));
+        assert_eq!(eval("angka_lokal('1,2,3')"),
+                   Err(Error::CanNotCast(to_value("1,2,3"), "float".to_owned())));
+    }
+
+    #[test]
+    fn test_strict_variables() {
+        let mut context = Context::new();
+        context.insert("harga".to_owned(), to_value(100));
+        context.insert("diskon".to_owned(), Value::Null);
+        let contexts = vec![context];
+        let strict = |source: &str| ExecOptions::new(&Expr::new(source)).contexts(&contexts).strict_variables(true).exec();
+        assert_eq!(strict("harga * 2"), Ok(to_value(200)));
+        assert_eq!(strict("diskon"), Ok(Value::Null));
+        assert_eq!(strict("hrga * 2"), Err(Error::VariableNotFound("hrga".to_owned())));
+        assert_eq!(strict("peta([1, 2], x => x + harga)"), Ok(to_value(vec![101, 102])));
+        assert_eq!(ExecOptions::new(&Expr::new("hrga")).contexts(&contexts).exec(), Ok(Value::Null));
+    }
+
+    #[test]
+    fn test_fixed_clock() {
+        let run = |source: &str, millis: i64| ExecOptions::new(&Expr::new(source)).clock(millis).exec();
+        assert_eq!(run("sekarang()", 1715934600123), Ok(to_value("2024-05-17T08:30:00.123Z")));
+        assert_eq!(run("sekarang('milidetik')", 1715934600123), Ok(to_value(1715934600123i64)));
+        assert_eq!(run("hari_ini()", 1715934600123), Ok(to_value("2024-05-17")));
+        assert_eq!(run("hari_ini()", 951782400000), Ok(to_value("2000-02-29")));
+        assert_eq!(run("sekarang()", -1), Ok(to_value("1969-12-31T23:59:59.999Z")));
+        assert_eq!(run("sekarang('jam')", 0), Err(Error::Custom("Unknown time format: jam".to_owned())));
+        assert_eq!(eval("panjang(sekarang()) == 24 && panjang(hari_ini()) == 10"), Ok(to_value(true)));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_date_parse_and_format() {
+        assert_eq!(eval("tanggal('2024-05-17')"), Ok(to_value("2024-05-17T00:00:00.000Z")));
+        assert_eq!(eval("tanggal('2024-05-17 08:30:00')"), Ok(to_value("2024-05-17T08:30:00.000Z")));
+        assert_eq!(eval("tanggal('2024-05-17T08:30:00+07:00')"), Ok(to_value("2024-05-17T08:30:00.000+07:00")));
+        assert_eq!(eval("tanggal('17/05/2024', '%d/%m/%Y')"), Ok(to_value("2024-05-17T00:00:00.000Z")));
+        assert_eq!(eval("tanggal('2024-13-01')"), Err(Error::InvalidDate("2024-13-01".to_owned())));
+        assert_eq!(eval("format_tanggal('2024-05-17', '%d %B %Y')"), Ok(to_value("17 May 2024")));
+        assert_eq!(eval("format_tanggal_id('2024-05-07')"), Ok(to_value("7 Mei 2024")));
+        assert_eq!(eval("format_tanggal_id('2024-05-17', '%A, %d %b %Y')"), Ok(to_value("Jumat, 17 Mei 2024")));
+        assert_eq!(eval("format_tanggal_id(tanggal('2024-08-18'), '%A')"), Ok(to_value("Minggu")));
+        assert_eq!(eval("format_tanggal('2024-05-17', '%Q')"), Err(Error::Custom("Invalid date format: %Q".to_owned())));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_date_arithmetic() {
+        assert_eq!(eval("tambah_hari('2024-05-17', 7)"), Ok(to_value("2024-05-24T00:00:00.000Z")));
+        assert_eq!(eval("tambah_hari('2024-03-01', -1)"), Ok(to_value("2024-02-29T00:00:00.000Z")));
+        assert_eq!(eval("tambah_bulan('2024-01-31', 1)"), Ok(to_value("2024-02-29T00:00:00.000Z")));
+        assert_eq!(eval("tambah_bulan('2024-05-17T08:30:00+07:00', -12)"), Ok(to_value("2023-05-17T08:30:00.000+07:00")));
+        assert_eq!(eval("selisih_hari('2024-05-17', '2024-04-17')"), Ok(to_value(30)));
+        assert_eq!(eval("selisih_hari('2024-04-17', '2024-05-17 12:00:00')"), Ok(to_value(-30)));
+        assert_eq!(eval("selisih_jam('2024-05-17T08:00:00+07:00', '2024-05-17 00:00:00')"), Ok(to_value(1)));
+        assert_eq!(eval("tambah_hari('2024-05-17', 1.5)"), Err(Error::ExpectedNumber));
+
+        let expr = Expr::new("selisih_hari(sekarang(), '2024-04-01') > 30");
+        assert_eq!(ExecOptions::new(&expr).clock(1715934600123).exec(), Ok(to_value(true)));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_date_components() {
+        let t = "tanggal('2024-05-17T08:45:00+07:00')";
+        let part = |name: &str| eval(&format!("{}({})", name, t));
+        assert_eq!(part("tahun"), Ok(to_value(2024)));
+        assert_eq!(part("bulan"), Ok(to_value(5)));
+        assert_eq!(part("hari"), Ok(to_value(17)));
+        assert_eq!(part("jam"), Ok(to_value(8)));
+        assert_eq!(part("menit"), Ok(to_value(45)));
+        assert_eq!(part("hari_minggu"), Ok(to_value(5)));
+        assert_eq!(eval(&format!("hari_minggu({}, benar)", t)), Ok(to_value("Jumat")));
+        assert_eq!(eval("hari_minggu('2024-05-19') == 7 && jam('2024-05-19') == 0"), Ok(to_value(true)));
+        assert_eq!(eval("tahun(123)"), Ok(to_value(1970)));
+        assert_eq!(eval("bulan(benar)"), Err(Error::ExpectedString));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz")]
+    fn test_to_zone() {
+        assert_eq!(eval("ke_zona('2024-05-17T02:30:00Z', 'Asia/Jakarta')"), Ok(to_value("2024-05-17T09:30:00.000+07:00")));
+        assert_eq!(eval("jam(ke_zona('2024-05-17T00:30:00Z', 'Asia/Makassar')) >= 9"), Ok(to_value(false)));
+        assert_eq!(eval("jam(ke_zona('2024-05-17T01:30:00Z', 'Asia/Makassar')) >= 9"), Ok(to_value(true)));
+        assert_eq!(eval("ke_zona('2024-01-15T12:00:00Z', 'Europe/Amsterdam')"), Ok(to_value("2024-01-15T13:00:00.000+01:00")));
+        assert_eq!(eval("ke_zona('2024-07-15T12:00:00Z', 'Europe/Amsterdam')"), Ok(to_value("2024-07-15T14:00:00.000+02:00")));
+        assert_eq!(eval("ke_zona('2024-05-17', 'Asia/Bandung')"), Err(Error::Custom("Unknown time zone: Asia/Bandung".to_owned())));
+    }
+
+    #[test]
+    fn test_string_compare() {
+        assert_eq!(eval("'abc' < 'abd'"), Ok(to_value(true)));
+        assert_eq!(eval("'b' > 'abc'"), Ok(to_value(true)));
+        assert_eq!(
+            Expr::new("nama >= 'M'").value("nama", "Siti").exec(),
+            Ok(to_value(true))
+        );
+        assert_eq!(eval("'M' <= 'M'"), Ok(to_value(true)));
+    }
+
+    #[test]
+    fn test_in() {
+        assert_eq!(eval("3 in untaian(1, 2, 3)"), Ok(to_value(true)));
+        assert_eq!(eval("4 in untaian(1, 2, 3)"), Ok(to_value(false)));
+        assert_eq!(eval("'lo' in 'halo'"), Ok(to_value(true)));
+    }
+
+    #[test]
+    fn test_in_object() {
+        let mut object = HashMap::new();
+        object.insert("key", "value");
+        assert_eq!(
+            Expr::new("'key' in object && !('value' in object)")
+                .value("object", object)
+                .exec(),
+            Ok(to_value(true))
+        );
+    }
+
+    #[test]
+    fn test_int_div() {
+        assert_eq!(eval("7 // 2"), Ok(to_value(3)));
+        assert_eq!(eval("-7 // 2"), Ok(to_value(-3)));
+        assert_eq!(eval("7.5 // 2"), Ok(to_value(3.0)));
+    }
+
+    #[test]
+    fn test_int_div_and_rem() {
+        assert_eq!(eval("-7 // 2 * 2 + -7 % 2"), Ok(to_value(-7)));
+    }
+
+    #[test]
+    fn test_division_semantics() {
+        let expr = Expr::new("7 / 2");
+        assert_eq!(ExecOptions::new(&expr).exec(), Ok(to_value(3.5)));
+        assert_eq!(ExecOptions::new(&expr).division(Division::Truncate).exec(),
+                   Ok(to_value(3)));
+        assert_eq!(ExecOptions::new(&expr).division(Division::Exact).exec(),
+                   Err(Error::InexactDivision(to_value(7), to_value(2))));
+
+        let expr = Expr::new("6 / -2 + 7.0 / 2").compile().unwrap();
+        assert_eq!(ExecOptions::new(&expr).division(Division::Exact).exec(),
+                   Ok(to_value(0.5)));
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        assert_eq!(eval("1 / 0"), Err(Error::DivisionByZero));
+        assert_eq!(eval("1.5 / 0.0"), Err(Error::DivisionByZero));
+        assert_eq!(eval("5 % 0"), Err(Error::DivisionByZero));
+        assert_eq!(eval("5 // (2 - 2)"), Err(Error::DivisionByZero));
+    }
+
     #[test]
     fn test_rem_1() {
         assert_eq!(eval("2 % 2"), Ok(to_value(0)));
@@ -252,6 +1640,93 @@ mod tests {
         assert_eq!(eval("23 % 5.5"), Ok(to_value(1.0)));
     }
 
+    #[test]
+    fn test_pow() {
+        assert_eq!(eval("2 ** 10"), Ok(to_value(1024)));
+        assert_eq!(eval("2 * 3 ** 2"), Ok(to_value(18)));
+        assert_eq!(eval("4 ** 0.5"), Ok(to_value(2.0)));
+        assert_eq!(eval("0 ** -1"), Err(Error::NotFinite));
+        assert_eq!(eval("(-8) ** 0.5"), Err(Error::NotFinite));
+        assert_eq!(eval("10.0 ** 400"), Err(Error::NotFinite));
+    }
+
+    #[test]
+    fn test_pow_right_associative() {
+        assert_eq!(eval("2 ** 3 ** 2"), Ok(to_value(512)));
+    }
+
+    #[test]
+    fn test_unary_minus() {
+        assert_eq!(eval("-5 + 3"), Ok(to_value(-2)));
+        assert_eq!(eval("2 * -3"), Ok(to_value(-6)));
+        assert_eq!(eval("2 * -3 + 1"), Ok(to_value(-5)));
+        assert_eq!(eval("5 - -3"), Ok(to_value(8)));
+        assert_eq!(eval("-(2 + 3)"), Ok(to_value(-5)));
+        assert_eq!(eval("-2 ** 2"), Ok(to_value(-4)));
+    }
+
+    #[test]
+    fn test_error_unary_without_operand() {
+        assert_eq!(eval("-"), Err(Error::StartWithNonValueOperator));
+        assert_eq!(eval("2 * -"), Err(Error::StartWithNonValueOperator));
+        assert_eq!(eval("- -"), Err(Error::StartWithNonValueOperator));
+        assert_eq!(eval("(-)"), Err(Error::StartWithNonValueOperator));
+    }
+
+    #[test]
+    fn test_unary_minus_in_function() {
+        assert_eq!(eval("min(-1, -2)"), Ok(to_value(-2)));
+        assert_eq!(eval("-9223372036854775808"), Ok(to_value(i64::MIN)));
+    }
+
+    #[test]
+    fn test_sub_and_mul_priority() {
+        assert_eq!(eval("10 - 2 * 3 + 1"), Ok(to_value(5)));
+    }
+
+    #[test]
+    fn test_bitwise() {
+        assert_eq!(eval("12 & 10"), Ok(to_value(8)));
+        assert_eq!(eval("12 | 3"), Ok(to_value(15)));
+        assert_eq!(eval("12 ^ 10"), Ok(to_value(6)));
+        assert_eq!(eval("1 << 4"), Ok(to_value(16)));
+        assert_eq!(eval("256 >> 4"), Ok(to_value(16)));
+    }
+
+    #[test]
+    fn test_number_literals() {
+        assert_eq!(eval("0xFF"), Ok(to_value(255)));
+        assert_eq!(eval("0o755"), Ok(to_value(493)));
+        assert_eq!(eval("0b1010 | 0b0101"), Ok(to_value(15)));
+        assert_eq!(eval("1_000_000 + 0.000_5"), Ok(to_value(1000000.0005)));
+    }
+
+    #[test]
+    fn test_scientific_notation() {
+        assert_eq!(eval("1.5e6"), Ok(to_value(1500000.0)));
+        assert_eq!(eval("2E-3"), Ok(to_value(0.002)));
+        assert_eq!(eval("1e2+1"), Ok(to_value(101.0)));
+        assert_eq!(eval("0x1e-3"), Ok(to_value(27)));
+        assert_eq!(eval("1e400"), Err(Error::NotFinite));
+        assert_eq!(eval("-1e400 + 1"), Err(Error::NotFinite));
+    }
+
+    #[test]
+    fn test_bitwise_priority() {
+        assert_eq!(
+            Expr::new("flags & 4 == 4 && flags & 2 == 0")
+                .value("flags", 5)
+                .exec(),
+            Ok(to_value(true))
+        );
+        assert_eq!(eval("1 + 1 << 2"), Ok(to_value(8)));
+    }
+
+    #[test]
+    fn test_bitwise_expected_number() {
+        assert_eq!(eval("1.5 & 1"), Err(Error::ExpectedNumber));
+    }
+
     #[test]
     fn test_and_1() {
         assert_eq!(eval("3 > 2 && 2 > 1"), Ok(to_value(true)));
@@ -287,6 +1762,30 @@ mod tests {
         assert_eq!(eval("3 < 2 || 2 > 1"), Ok(to_value(true)));
     }
 
+    #[test]
+    fn test_ternary() {
+        assert_eq!(
+            Expr::new("nilai > 70 ? 'lulus' : 'gagal'")
+                .value("nilai", 80)
+                .exec(),
+            Ok(to_value("lulus"))
+        );
+        assert_eq!(eval("1 > 2 ? 1 : 2 + 3"), Ok(to_value(5)));
+        assert_eq!(eval("false ? 1 : false ? 2 : 3"), Ok(to_value(3)));
+        assert_eq!(eval("true ? false ? 1 : 2 : 3"), Ok(to_value(2)));
+    }
+
+    #[test]
+    fn test_ternary_is_lazy() {
+        assert_eq!(eval("true ? 1 : tidak_ada()"), Ok(to_value(1)));
+    }
+
+    #[test]
+    fn test_error_unpaired_ternary() {
+        assert_eq!(eval("true ? 1"), Err(Error::UnpairedTernary));
+        assert_eq!(eval("true ? (1 : 2)"), Err(Error::UnpairedTernary));
+    }
+
     #[test]
     fn test_not() {
         assert_eq!(eval("!false"), Ok(to_value(true)));
@@ -296,6 +1795,34 @@ mod tests {
         assert_eq!(eval("!(1 == 2) == true"), Ok(to_value(true)));
     }
 
+    #[test]
+    fn test_word_operators() {
+        assert_eq!(
+            Expr::new("umur > 17 dan aktif == true")
+                .value("umur", 20)
+                .value("aktif", true)
+                .exec(),
+            Ok(to_value(true))
+        );
+        assert_eq!(eval("1 > 2 atau bukan (1 == 2)"), Ok(to_value(true)));
+        assert_eq!(eval("bukan"), Err(Error::StartWithNonValueOperator));
+        assert_eq!(eval("benar dan bukan"), Err(Error::StartWithNonValueOperator));
+    }
+
+    #[test]
+    fn test_indonesian_literals() {
+        assert_eq!(
+            Expr::new("umur > 17 dan aktif == benar")
+                .value("umur", 20)
+                .value("aktif", true)
+                .exec(),
+            Ok(to_value(true))
+        );
+        assert_eq!(eval("salah || bukan salah"), Ok(to_value(true)));
+        assert_eq!(eval("nihil == null"), Ok(to_value(true)));
+        assert_eq!(Expr::new("nihil").value("nihil", 1).exec(), Ok(Value::Null));
+    }
+
     #[test]
     fn test_not_and_brackets() {
         assert_eq!(eval("(!(1 == 2)) == true"), Ok(to_value(true)));
@@ -341,6 +1868,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_optional_chaining() {
+        let mut object = HashMap::new();
+        object.insert("foo", Value::Null);
+        object.insert("bar", to_value(5));
+        assert_eq!(
+            Expr::new("object?.foo?.baz")
+                .value("object", &object)
+                .exec(),
+            Ok(Value::Null)
+        );
+        assert_eq!(
+            Expr::new("object.bar?.baz").value("object", &object).exec(),
+            Ok(Value::Null)
+        );
+        assert_eq!(
+            Expr::new("object.bar.baz").value("object", &object).exec(),
+            Err(Error::ExpectedObject)
+        );
+    }
+
     #[test]
     fn test_path() {
         assert_eq!(Expr::new("untaian[2-2].foo[2-2]").exec(), Ok(Value::Null));
@@ -358,6 +1906,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_array_negative_index() {
+        let array = vec!["hello", "world", "!"];
+        assert_eq!(
+            Expr::new("untaian[-1] == '!' && untaian[-3] == 'hello'")
+                .value("untaian", &array)
+                .exec(),
+            Ok(to_value(true))
+        );
+        assert_eq!(
+            Expr::new("untaian[-4]").value("untaian", &array).exec(),
+            Ok(Value::Null)
+        );
+    }
+
+    #[test]
+    fn test_array_slice() {
+        let array = vec![0, 1, 2, 3, 4, 5];
+        let slice = |expr: &str| Expr::new(expr).value("untaian", &array).exec();
+        assert_eq!(slice("untaian[1:3]"), Ok(to_value(vec![1, 2])));
+        assert_eq!(slice("untaian[:2]"), Ok(to_value(vec![0, 1])));
+        assert_eq!(slice("untaian[4:]"), Ok(to_value(vec![4, 5])));
+        assert_eq!(slice("untaian[-2:]"), Ok(to_value(vec![4, 5])));
+        assert_eq!(slice("max(untaian[1:3])"), Ok(to_value(2)));
+    }
+
+    #[test]
+    fn test_string_slice() {
+        assert_eq!(
+            Expr::new("teks[1:4]").value("teks", "Halo").exec(),
+            Ok(to_value("alo"))
+        );
+    }
+
+    #[test]
+    fn test_array_access_in_brackets() {
+        let array = vec![1, 2, 3];
+        assert_eq!(
+            Expr::new("(untaian[1]) + min(untaian[2], 5)")
+                .value("untaian", array)
+                .exec(),
+            Ok(to_value(5))
+        );
+    }
+
     #[test]
     fn test_builtin_is_empty() {
         assert_eq!(
@@ -378,6 +1971,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_spread() {
+        assert_eq!(
+            Expr::new("max(1, ...nilai, 9)")
+                .value("nilai", vec![4, 20, -3])
+                .exec(),
+            Ok(to_value(20))
+        );
+        assert_eq!(eval("panjang(...untaian('x'))"), Ok(to_value(1)));
+        assert_eq!(eval("min(...5)"), Err(Error::ExpectedArray));
+    }
+
+    #[test]
+    fn test_pipeline() {
+        assert_eq!(eval("untaian(3, 1, 2) |> min"), Ok(to_value(1)));
+        assert_eq!(eval("(0..5) |> max |> min(2)"), Ok(to_value(2)));
+        assert_eq!(eval("1 + 2 |> min(5) > 2"), Ok(to_value(true)));
+        assert_eq!(eval("2 |> 3"), Err(Error::InvalidPipeline));
+        assert_eq!(eval("1 |>"), Err(Error::InvalidPipeline));
+        assert_eq!(eval("|> min"), Err(Error::StartWithNonValueOperator));
+    }
+
     #[test]
     fn test_custom_function() {
         assert_eq!(